@@ -1,19 +1,31 @@
-use crate::commands::games::ActiveGame;
-use crate::commands::{self, LineStyle};
+use crate::commands::editor::EditorAction;
+use crate::commands::games::{self, Game};
+use crate::commands::links::{self, Span};
+use crate::commands::pager::{Pager, PagerAction};
+use crate::commands::prompt::PromptSession;
+use crate::commands::{self, FullScreen, LineStyle};
+use crate::components::editor::EditorView;
 use crate::components::matrix_rain::MatrixRain;
 use crate::components::mobile_toolbar::MobileToolbar;
+use crate::components::pager::PagerView;
 use crate::components::typewriter::TypewriterLine;
 use crate::components::welcome::WelcomeBanner;
-use crate::filesystem::builder;
+use crate::filesystem::model::display_path;
+use crate::filesystem::procfs::SessionSource;
+use crate::filesystem::{builder, overlay};
+use crate::i18n;
 use crate::parser::tokenizer;
+use crate::state::shell::Shell;
 use crate::state::theme::ThemeState;
 use leptos::prelude::*;
 use wasm_bindgen::JsCast;
-use web_sys::HtmlInputElement;
 
 #[derive(Clone)]
 struct OutputBlock {
-    id: u32,
+    /// Prompt path at the time the command ran
+    cwd: String,
+    /// The question answered, when the input belonged to a prompt session
+    question: Option<String>,
     command: String,
     lines: Vec<(String, LineStyle)>,
     animate: bool,
@@ -23,6 +35,7 @@ struct OutputBlock {
 pub fn App() -> impl IntoView {
     let input_ref = NodeRef::<leptos::html::Input>::new();
     let output_ref = NodeRef::<leptos::html::Div>::new();
+    let full_screen_ref = NodeRef::<leptos::html::Div>::new();
 
     let theme = ThemeState::new();
 
@@ -32,9 +45,59 @@ pub fn App() -> impl IntoView {
     let (input_value, set_input_value) = signal(String::new());
     let (history, set_history) = signal(Vec::<String>::new());
     let (history_idx, set_history_idx) = signal::<Option<usize>>(None);
-    let (active_game, set_active_game) = signal::<Option<ActiveGame>>(None);
-    let cwd = RwSignal::new("~".to_string());
-    let fs = RwSignal::new(builder::build_default_fs());
+    let active_game = RwSignal::new(None::<Box<dyn Game>>);
+    let full_screen = RwSignal::new(None::<FullScreen>);
+    let session = RwSignal::new(None::<PromptSession>);
+    let shell = RwSignal::new(Shell::new());
+    i18n::init();
+    let fs = RwSignal::new({
+        let mut vfs = builder::build_default_fs();
+        vfs.apply_overlay(overlay::load());
+        vfs.session = Some(SessionSource { theme, history });
+        vfs
+    });
+    // Index everything up front so the first search is instant
+    shell.update(|shell| fs.with_untracked(|vfs| shell.search.refresh(vfs)));
+
+    // Persist the visitor's changes whenever the filesystem changes
+    Effect::new(move || {
+        fs.with(|vfs| overlay::save(&vfs.overlay));
+    });
+
+    // Same for a game in progress, which picks up again after a reload
+    if let Some(game) = games::load() {
+        let mut lines = vec![(format!("  {}", i18n::t("game.resumed")), LineStyle::Muted)];
+        lines.extend(game.start_output().lines);
+        set_output.set(vec![OutputBlock {
+            cwd: shell.with_untracked(|s| display_path(&s.cwd)),
+            question: None,
+            command: game.name().to_string(),
+            lines,
+            animate: false,
+        }]);
+        active_game.set(Some(game));
+    }
+    Effect::new(move || {
+        active_game.with(|game| games::save(game.as_deref()));
+    });
+
+    // A post's permalink, #blog/<slug>, opens the page reading that post
+    if let Some(slug) = blog_route() {
+        let args = ["read".to_string(), slug.clone()];
+        let result = fs.with_untracked(|vfs| commands::blog::blog(vfs, &args));
+        if Pager::should_page(result.lines.len()) {
+            full_screen.set(Some(FullScreen::Pager(Pager::new(result.lines.clone()))));
+        }
+        set_output.update(|out| {
+            out.push(OutputBlock {
+                cwd: shell.with_untracked(|s| display_path(&s.cwd)),
+                question: None,
+                command: format!("blog read {}", slug),
+                lines: result.lines,
+                animate: false,
+            })
+        });
+    }
 
     Effect::new(move || {
         output.track();
@@ -70,24 +133,62 @@ pub fn App() -> impl IntoView {
         }
     });
 
+    let in_full_screen = move || full_screen.with(Option::is_some);
+    let prompt_path = move || shell.with(|s| display_path(&s.cwd));
+
     // This runs when the user presses Enter
     let on_keydown = move |ev: web_sys::KeyboardEvent| {
+        // A running game gets the first look at every key but Enter
+        let key = ev.key();
+        if key != "Enter" && active_game.with_untracked(Option::is_some) {
+            let handled = active_game
+                .try_update_untracked(|game| {
+                    let game = game.as_mut()?;
+                    Some((game.handle_key(&key)?, game.is_finished()))
+                })
+                .flatten();
+            if let Some((result, game_finished)) = handled {
+                ev.prevent_default();
+                set_output.update(|out| {
+                    for block in out.iter_mut() {
+                        block.animate = false;
+                    }
+                    out.push(OutputBlock {
+                        cwd: prompt_path(),
+                        question: None,
+                        command: key,
+                        lines: result.lines,
+                        animate: false,
+                    });
+                });
+                if game_finished {
+                    active_game.set(None);
+                } else {
+                    active_game.notify();
+                }
+                return;
+            }
+        }
+
         match ev.key().as_str() {
             "Enter" => {
                 let cmd = input_value.get();
                 let trimmed = cmd.trim().to_string();
 
-                if active_game.get().is_some() && trimmed.to_lowercase() == "quit" {
-                    set_active_game.set(None);
+                if active_game.with(Option::is_some) && trimmed.to_lowercase() == "quit" {
+                    active_game.set(None);
                     set_output.update(|out| {
                         for block in out.iter_mut() {
                             block.animate = false;
                         }
-                        let id = out.len() as u32;
                         out.push(OutputBlock {
-                            id,
+                            cwd: prompt_path(),
+                            question: None,
                             command: trimmed.clone(),
-                            lines: vec![("  Game exited.".to_string(), LineStyle::Accent)],
+                            lines: vec![(
+                                format!("  {}", i18n::t("game.exited")),
+                                LineStyle::Accent,
+                            )],
                             animate: false,
                         });
                     });
@@ -99,17 +200,20 @@ pub fn App() -> impl IntoView {
                     return;
                 }
 
-                if let Some(mut game) = active_game.get() {
-                    let result = game.handle_input(&trimmed);
-                    let game_finished = game.is_finished();
-
+                let played = active_game
+                    .try_update(|game| {
+                        let game = game.as_mut()?;
+                        Some((game.handle_input(&trimmed), game.is_finished()))
+                    })
+                    .flatten();
+                if let Some((result, game_finished)) = played {
                     set_output.update(|out| {
                         for block in out.iter_mut() {
                             block.animate = false;
                         }
-                        let id = out.len() as u32;
                         out.push(OutputBlock {
-                            id,
+                            cwd: prompt_path(),
+                            question: None,
                             command: trimmed.clone(),
                             lines: result.lines,
                             animate: false,
@@ -117,9 +221,7 @@ pub fn App() -> impl IntoView {
                     });
 
                     if game_finished {
-                        set_active_game.set(None);
-                    } else {
-                        set_active_game.set(Some(game));
+                        active_game.set(None);
                     }
 
                     if !trimmed.is_empty() {
@@ -130,21 +232,68 @@ pub fn App() -> impl IntoView {
                     return;
                 }
 
-                let mut current_cwd = cwd.get();
+                // Answers to a prompt session never reach the shell or its history
+                if let Some(mut current) = session.get() {
+                    let question = current.question();
+                    let result = current.handle_input(&cmd);
+                    set_output.update(|out| {
+                        for block in out.iter_mut() {
+                            block.animate = false;
+                        }
+                        out.push(OutputBlock {
+                            cwd: prompt_path(),
+                            question: Some(question),
+                            command: trimmed,
+                            lines: result.lines,
+                            animate: false,
+                        });
+                    });
+                    session.set((!current.is_finished()).then_some(current));
+                    set_input_value.set(String::new());
+                    return;
+                }
+
+                let prompt = prompt_path();
                 let parsed = tokenizer::parse(&cmd);
-                let result = commands::dispatch(
-                    &parsed,
-                    &fs.get(),
-                    &mut current_cwd,
-                    &theme,
-                    &history.get(),
-                );
-                cwd.set(current_cwd);
+                let past = history.get();
+                // In place, so neither the search index nor the filesystem is
+                // cloned on every command. The filesystem only counts as
+                // changed, and gets saved, when its overlay does.
+                let Some((result, fs_changed)) = shell
+                    .try_update(|current_shell| {
+                        fs.try_update_untracked(|vfs| {
+                            let before = vfs.overlay.clone();
+                            let result =
+                                commands::dispatch(&parsed, vfs, current_shell, &theme, &past);
+                            let changed = vfs.overlay != before;
+                            (result, changed)
+                        })
+                    })
+                    .flatten()
+                else {
+                    return;
+                };
+                if fs_changed {
+                    fs.notify();
+                }
 
                 if let Some(game) = result.start_game {
-                    set_active_game.set(Some(game));
+                    active_game.set(Some(game));
+                }
+                if let Some(started) = result.prompt {
+                    session.set(Some(started));
                 }
 
+                if let Some(mode) = result.full_screen {
+                    full_screen.set(Some(mode));
+                } else if Pager::should_page(result.lines.len()) {
+                    // Too long for the screen: page it, and keep a copy in the scrollback
+                    full_screen.set(Some(FullScreen::Pager(Pager::new(result.lines.clone()))));
+                }
+
+                // No point typing out output that is hidden behind a full-screen mode
+                let animate = result.typewriter && !in_full_screen();
+
                 if result.clear_screen {
                     set_output.set(vec![]);
                 } else if !parsed.command.is_empty() || !result.lines.is_empty() {
@@ -153,12 +302,12 @@ pub fn App() -> impl IntoView {
                         for block in out.iter_mut() {
                             block.animate = false;
                         }
-                        let id = out.len() as u32;
                         out.push(OutputBlock {
-                            id,
+                            cwd: prompt,
+                            question: None,
                             command: cmd.trim().to_string(),
                             lines: result.lines,
-                            animate: result.typewriter,
+                            animate,
                         })
                     });
                 }
@@ -173,6 +322,27 @@ pub fn App() -> impl IntoView {
                 set_history_idx.set(None);
                 set_input_value.set(String::new());
             }
+            "Escape" if session.with(Option::is_some) => {
+                let Some(mut current) = session.get() else {
+                    return;
+                };
+                let question = current.question();
+                let result = current.cancel();
+                set_output.update(|out| {
+                    for block in out.iter_mut() {
+                        block.animate = false;
+                    }
+                    out.push(OutputBlock {
+                        cwd: prompt_path(),
+                        question: Some(question),
+                        command: "^[".to_string(),
+                        lines: result.lines,
+                        animate: false,
+                    });
+                });
+                session.set(None);
+                set_input_value.set(String::new());
+            }
             "ArrowUp" => {
                 ev.prevent_default();
                 let h = history.get();
@@ -232,7 +402,7 @@ pub fn App() -> impl IntoView {
                     if parts.len() == 2 {
                         let partial = parts[1];
                         let current_fs = fs.get();
-                        let current_cwd = cwd.get();
+                        let current_cwd = shell.with(|s| s.cwd.clone());
 
                         // Figure out the directory and partial name to match
                         let (dir_path, name_prefix) = if partial.contains('/') {
@@ -248,7 +418,9 @@ pub fn App() -> impl IntoView {
                         };
 
                         // Get matching children
-                        if let Some(entries) = current_fs.ls(&dir_path) {
+                        // Dotfiles only complete once the visitor has typed the dot
+                        let show_hidden = name_prefix.starts_with('.');
+                        if let Some(entries) = current_fs.ls(&dir_path, show_hidden) {
                             let matches: Vec<String> = entries
                                 .iter()
                                 .filter(|(name, _)| name.starts_with(&name_prefix))
@@ -278,15 +450,53 @@ pub fn App() -> impl IntoView {
         }
     };
 
-    let line_class = |style: &LineStyle| match style {
-        LineStyle::Normal => "line-normal",
-        LineStyle::Accent => "line-accent",
-        LineStyle::Error => "line-error",
-        LineStyle::Muted => "line-muted",
+    // Raw key presses while a full-screen mode owns the terminal
+    let on_full_screen_keydown = move |ev: web_sys::KeyboardEvent| {
+        let Some(mode) = full_screen.get() else {
+            return;
+        };
+        let ctrl = ev.ctrl_key() || ev.meta_key();
+
+        match mode {
+            FullScreen::Editor(mut editor) => {
+                ev.prevent_default();
+                match editor.handle_key(&ev.key(), ctrl) {
+                    EditorAction::Save => fs.update(|vfs| editor.save(vfs)),
+                    EditorAction::Exit => {
+                        full_screen.set(None);
+                        return;
+                    }
+                    EditorAction::None => {}
+                }
+                full_screen.set(Some(FullScreen::Editor(editor)));
+            }
+            FullScreen::Pager(mut pager) => {
+                ev.prevent_default();
+                match pager.handle_key(&ev.key()) {
+                    PagerAction::Quit => full_screen.set(None),
+                    PagerAction::None => full_screen.set(Some(FullScreen::Pager(pager))),
+                }
+            }
+        }
     };
 
+    // Keep keyboard focus on whichever surface is active
+    Effect::new(move || {
+        if in_full_screen() {
+            if let Some(el) = full_screen_ref.get() {
+                let _ = el.focus();
+            }
+        } else if let Some(el) = input_ref.get() {
+            let _ = el.focus();
+        }
+    });
+
     let focus_input = move |_| {
-        if let Some(el) = input_ref.get() {
+        if in_full_screen() {
+            if let Some(el) = full_screen_ref.get() {
+                let _ = el.focus();
+            }
+        } else if let Some(el) = input_ref.get() {
             let _ = el.focus();
         }
     };
@@ -305,7 +515,23 @@ pub fn App() -> impl IntoView {
         >
             {move || theme.matrix_rain.get().then(|| view! { <MatrixRain /> })}
             <div class="terminal">
-                <div class="terminal-output" node_ref=output_ref>
+                <div
+                    class="full-screen"
+                    tabindex="0"
+                    node_ref=full_screen_ref
+                    on:keydown=on_full_screen_keydown
+                    style:display=move || if in_full_screen() { "flex" } else { "none" }
+                >
+                    {move || full_screen.get().map(|mode| match mode {
+                        FullScreen::Editor(editor) => view! { <EditorView editor=editor/> }.into_any(),
+                        FullScreen::Pager(pager) => view! { <PagerView pager=pager/> }.into_any(),
+                    })}
+                </div>
+                <div
+                    class="terminal-output"
+                    node_ref=output_ref
+                    style:display=move || if in_full_screen() { "none" } else { "block" }
+                >
                     <WelcomeBanner />
                     {move || output.get().into_iter().map(|block| {
                         let lines = block.lines.clone();
@@ -313,25 +539,93 @@ pub fn App() -> impl IntoView {
                         view! {
                             <div>
                                 <div class="output-command">
-                                    <span class="prompt-user">"visitor"</span>
-                                    <span class="prompt-at">"@"</span>
-                                    <span class="prompt-host">"portfolio"</span>
-                                    <span class="prompt-colon">":"</span>
-                                    <span class="prompt-path">"~"</span>
-                                    <span class="prompt-dollar">"$ "</span>
+                                    {match block.question {
+                                        Some(question) => view! {
+                                            <span class="prompt-question">{question}</span>
+                                        }.into_any(),
+                                        None => view! {
+                                            <span class="prompt-user">"visitor"</span>
+                                            <span class="prompt-at">"@"</span>
+                                            <span class="prompt-host">"portfolio"</span>
+                                            <span class="prompt-colon">":"</span>
+                                            <span class="prompt-path">{block.cwd}</span>
+                                            <span class="prompt-dollar">"$ "</span>
+                                        }.into_any(),
+                                    }}
                                     {block.command}
                                 </div>
                                 <div>
                                     {
                                         let mut cumulative_delay: i32 = 0;
                                         lines.into_iter().map(|(text, style)| {
-                                            let class = line_class(&style);
+                                            let class = style.css_class();
                                             let delay = cumulative_delay;
                                             cumulative_delay += (text.len() as i32) * 12 + 100;
-                                            view! {
-                                                <div class=class>
-                                                    <TypewriterLine text=text animate=should_animate delay_ms=delay/>
-                                                </div>
+                                            match style {
+                                                LineStyle::Link(href) => view! {
+                                                    <div class=class>
+                                                        <a href=href target="_blank" rel="noopener noreferrer">
+                                                            <TypewriterLine text=text animate=should_animate delay_ms=delay/>
+                                                        </a>
+                                                    </div>
+                                                }.into_any(),
+                                                LineStyle::Image(src) => view! {
+                                                    <div class=class>
+                                                        <img src=src alt=text/>
+                                                    </div>
+                                                }.into_any(),
+                                                LineStyle::Highlight(marks) => {
+                                                    let mut pieces = Vec::new();
+                                                    let mut last = 0;
+                                                    for (start, end) in marks {
+                                                        pieces.push((text[last..start].to_string(), false));
+                                                        pieces.push((text[start..end].to_string(), true));
+                                                        last = end;
+                                                    }
+                                                    pieces.push((text[last..].to_string(), false));
+
+                                                    let mut span_delay = delay;
+                                                    let spans = pieces.into_iter().filter(|(piece, _)| !piece.is_empty()).map(|(piece, marked)| {
+                                                        let start = span_delay;
+                                                        span_delay += (piece.len() as i32) * 12;
+                                                        if marked {
+                                                            view! {
+                                                                <mark class="search-match">
+                                                                    <TypewriterLine text=piece animate=should_animate delay_ms=start/>
+                                                                </mark>
+                                                            }.into_any()
+                                                        } else {
+                                                            view! {
+                                                                <TypewriterLine text=piece animate=should_animate delay_ms=start/>
+                                                            }.into_any()
+                                                        }
+                                                    }).collect::<Vec<_>>();
+                                                    view! { <div class=class>{spans}</div> }.into_any()
+                                                }
+                                                _ => {
+                                                    // Type each piece in turn, as if it were one line
+                                                    let mut span_delay = delay;
+                                                    let spans = links::spans(&text).into_iter().map(|span| {
+                                                        let start = span_delay;
+                                                        match span {
+                                                            Span::Text(text) => {
+                                                                span_delay += (text.len() as i32) * 12;
+                                                                view! {
+                                                                    <TypewriterLine text=text animate=should_animate delay_ms=start/>
+                                                                }.into_any()
+                                                            }
+                                                            Span::Link { href, label } => {
+                                                                span_delay += (label.len() as i32) * 12;
+                                                                view! {
+                                                                    <a class="inline-link" href=href target="_blank" rel="noopener noreferrer">
+                                                                        <TypewriterLine text=label animate=should_animate delay_ms=start/>
+                                                                    </a>
+                                                                }.into_any()
+                                                            }
+                                                        }
+                                                    }).collect::<Vec<_>>();
+                                                    view! { <div class=class>{spans}</div> }.into_any()
+                                                }
                                             }
                                         }).collect::<Vec<_>>()
                                     }
@@ -341,13 +635,23 @@ pub fn App() -> impl IntoView {
                     }).collect::<Vec<_>>()}
                     <div class="scroll-anchor"></div>
                 </div>
-                <div class="input-line">
-                    <span class="prompt-user">"visitor"</span>
-                    <span class="prompt-at">"@"</span>
-                    <span class="prompt-host">"portfolio"</span>
-                    <span class="prompt-colon">":"</span>
-                    <span class="prompt-path">{move || cwd.get()}</span>
-                    <span class="prompt-dollar">"$ "</span>
+                <div
+                    class="input-line"
+                    style:display=move || if in_full_screen() { "none" } else { "flex" }
+                >
+                    {move || match session.with(|s| s.as_ref().map(PromptSession::question)) {
+                        Some(question) => view! {
+                            <span class="prompt-question">{question}</span>
+                        }.into_any(),
+                        None => view! {
+                            <span class="prompt-user">"visitor"</span>
+                            <span class="prompt-at">"@"</span>
+                            <span class="prompt-host">"portfolio"</span>
+                            <span class="prompt-colon">":"</span>
+                            <span class="prompt-path">{prompt_path}</span>
+                            <span class="prompt-dollar">"$ "</span>
+                        }.into_any(),
+                    }}
                     <input
                         type="text"
                         class="terminal-input"
@@ -368,6 +672,14 @@ pub fn App() -> impl IntoView {
     }
 }
 
+/// The slug in a `#blog/<slug>` location hash.
+fn blog_route() -> Option<String> {
+    let hash = web_sys::window()?.location().hash().ok()?;
+    let slug = hash.strip_prefix("#blog/")?;
+    let slug = js_sys::decode_uri_component(slug).ok()?.as_string()?;
+    (!slug.is_empty()).then_some(slug)
+}
+
 fn request_animation_frame(f: impl FnOnce() + 'static) {
     let cb = wasm_bindgen::closure::Closure::once_into_js(f);
     web_sys::window()
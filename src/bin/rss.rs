@@ -0,0 +1,24 @@
+// Writes the blog's rss.xml. Trunk runs this after each build (see
+// Trunk.toml), which puts the feed next to index.html in the output.
+
+#[path = "../portfolio/blog.rs"]
+mod blog;
+
+use std::path::PathBuf;
+use std::{env, fs, process};
+
+const DOMAIN: &str = include_str!("../../CNAME");
+
+fn main() {
+    let out = env::args()
+        .nth(1)
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("TRUNK_STAGING_DIR").map(|dir| PathBuf::from(dir).join("rss.xml")))
+        .unwrap_or_else(|| PathBuf::from("rss.xml"));
+
+    let site = format!("https://{}", DOMAIN.trim());
+    if let Err(err) = fs::write(&out, blog::rss(blog::posts(), &site)) {
+        eprintln!("rss: cannot write {}: {}", out.display(), err);
+        process::exit(1);
+    }
+}
@@ -0,0 +1,499 @@
+use crate::commands::{portfolio::this_month, search, CommandOutput, LineStyle};
+use crate::filesystem::model::VirtualFs;
+use crate::i18n;
+use crate::portfolio::model::{Experience, Portfolio, Project, Skill};
+use crate::portfolio::text::{self, CATEGORIES};
+use crate::portfolio::{blog, portfolio};
+use crate::state::shell::Shell;
+
+/// A kind of question `ask` knows how to answer.
+struct Intent {
+    /// Beginnings of words that suggest a question is about this
+    stems: &'static [&'static str],
+    /// Where the full answer lives
+    command: &'static str,
+    answer: fn(&Portfolio) -> Vec<(String, LineStyle)>,
+}
+
+const INTENTS: &[Intent] = &[
+    Intent {
+        stems: &[
+            "languag",
+            "skill",
+            "know",
+            "stack",
+            "tech",
+            "tool",
+            "framework",
+            "program",
+            "cod",
+            "expert",
+            "proficien",
+        ],
+        command: "skills",
+        answer: skills_answer,
+    },
+    Intent {
+        stems: &[
+            "work",
+            "job",
+            "compan",
+            "employ",
+            "experience",
+            "career",
+            "role",
+            "position",
+        ],
+        command: "experience",
+        answer: experience_answer,
+    },
+    Intent {
+        stems: &[
+            "project", "built", "build", "made", "make", "creat", "app", "side",
+        ],
+        command: "projects",
+        answer: projects_answer,
+    },
+    Intent {
+        stems: &[
+            "reach", "contact", "email", "mail", "hire", "touch", "linkedin", "github", "messag",
+            "talk",
+        ],
+        command: "contact",
+        answer: contact_answer,
+    },
+    Intent {
+        stems: &["who", "yourself", "background", "bio", "introduc", "name"],
+        command: "about",
+        answer: about_answer,
+    },
+    Intent {
+        stems: &["resume", "cv", "pdf"],
+        command: "resume",
+        answer: resume_answer,
+    },
+    Intent {
+        stems: &["blog", "post", "articl", "writ"],
+        command: "blog",
+        answer: blog_answer,
+    },
+];
+
+/// Words too common to say anything about what a question is after.
+const STOP_WORDS: &[&str] = &[
+    "a", "about", "an", "and", "are", "at", "can", "did", "do", "does", "for", "have", "how", "i",
+    "in", "is", "it", "me", "my", "of", "on", "or", "tell", "that", "the", "this", "to", "what",
+    "when", "where", "which", "with", "you", "your",
+];
+
+pub fn ask(fs: &VirtualFs, shell: &mut Shell, args: &[String]) -> CommandOutput {
+    let question = args.join(" ");
+    let words: Vec<String> = search::words(&question)
+        .into_iter()
+        .map(|(_, word)| word.to_lowercase())
+        .collect();
+    if words.is_empty() {
+        let mut lines = vec![(
+            i18n::tr("shell.usage", &[("usage", "ask <question>")]),
+            LineStyle::Error,
+        )];
+        lines.extend(
+            i18n::t("ask.examples")
+                .lines()
+                .map(|line| (format!("  {}", line), LineStyle::Muted)),
+        );
+        return CommandOutput::new(lines, false, false);
+    }
+
+    let data = portfolio();
+    let mut scores: Vec<(usize, &Intent)> = INTENTS
+        .iter()
+        .map(|intent| {
+            let hits = words
+                .iter()
+                .filter(|word| intent.stems.iter().any(|stem| word.starts_with(stem)))
+                .count();
+            (hits, intent)
+        })
+        .collect();
+    scores.sort_by_key(|(hits, _)| std::cmp::Reverse(*hits));
+    let wants_projects = scores
+        .iter()
+        .any(|(hits, intent)| *hits > 0 && intent.command == "projects");
+
+    // Something named outright gets a specific answer
+    let specific = if let Some(project) = mentioned_project(data, &words) {
+        Some((
+            project_answer(project),
+            format!("projects {}", project.slug),
+        ))
+    } else if let Some(job) = mentioned_job(data, &words) {
+        let first = job.company.split_whitespace().next().unwrap_or_default();
+        Some((
+            job_answer(job),
+            format!("experience {}", first.to_lowercase()),
+        ))
+    } else if let [skill, ..] = mentioned_skills(data, &words)[..] {
+        if wants_projects {
+            Some((
+                built_with_answer(data, skill),
+                format!("search {}", skill.name.to_lowercase()),
+            ))
+        } else {
+            let lines = mentioned_skills(data, &words)
+                .into_iter()
+                .flat_map(|skill| skill_answer(data, skill))
+                .collect();
+            Some((lines, format!("skills --category {}", skill.category)))
+        }
+    } else {
+        None
+    };
+
+    let (mut lines, command) = match specific {
+        Some(found) => found,
+        // Only answer when one intent clearly stands out
+        None => match scores.as_slice() {
+            [(best, intent), (second, _), ..] if *best > 0 && best > second => {
+                ((intent.answer)(data), intent.command.to_string())
+            }
+            _ => return unsure(fs, shell, &words, &scores),
+        },
+    };
+
+    lines.insert(0, (String::new(), LineStyle::Normal));
+    lines.push((String::new(), LineStyle::Normal));
+    lines.push((
+        format!("  {}", i18n::tr("ask.more", &[("command", &command)])),
+        LineStyle::Muted,
+    ));
+    lines.push((String::new(), LineStyle::Normal));
+    CommandOutput::new(lines, false, true)
+}
+
+/// Point at the commands most likely to have the answer: any intents the
+/// question touched, then the best search matches.
+fn unsure(
+    fs: &VirtualFs,
+    shell: &mut Shell,
+    words: &[String],
+    scores: &[(usize, &Intent)],
+) -> CommandOutput {
+    let mut suggestions: Vec<String> = scores
+        .iter()
+        .filter(|(hits, _)| *hits > 0)
+        .map(|(_, intent)| intent.command.to_string())
+        .collect();
+    let terms: Vec<String> = words
+        .iter()
+        .filter(|word| word.len() > 2 && !STOP_WORDS.contains(&word.as_str()))
+        .cloned()
+        .collect();
+    for command in shell.search.suggest(fs, &terms, 3) {
+        if !suggestions.contains(&command) {
+            suggestions.push(command);
+        }
+    }
+    suggestions.truncate(4);
+
+    let mut lines = vec![(String::new(), LineStyle::Normal)];
+    if suggestions.is_empty() {
+        lines.push((format!("  {}", i18n::t("ask.dont_know")), LineStyle::Normal));
+    } else {
+        lines.push((format!("  {}", i18n::t("ask.not_sure")), LineStyle::Normal));
+        lines.extend(
+            suggestions
+                .into_iter()
+                .map(|command| (format!("    {}", command), LineStyle::Accent)),
+        );
+    }
+    lines.push((String::new(), LineStyle::Normal));
+    lines.push((format!("  {}", i18n::t("ask.tip")), LineStyle::Muted));
+    lines.push((String::new(), LineStyle::Normal));
+    CommandOutput::new(lines, false, true)
+}
+
+fn mentioned_skills<'a>(data: &'a Portfolio, words: &[String]) -> Vec<&'a Skill> {
+    data.skills
+        .iter()
+        .filter(|skill| words.contains(&skill.name.to_lowercase()))
+        .collect()
+}
+
+/// A company is mentioned by a word of its name or by its initials.
+fn mentioned_job<'a>(data: &'a Portfolio, words: &[String]) -> Option<&'a Experience> {
+    data.experience.iter().find(|job| {
+        let initials: String = job
+            .company
+            .split_whitespace()
+            .filter_map(|word| word.chars().next())
+            .collect::<String>()
+            .to_lowercase();
+        let named = search::words(&job.company)
+            .into_iter()
+            .map(|(_, word)| word.to_lowercase())
+            .any(|word| word.len() > 3 && words.contains(&word));
+        named || (initials.len() > 1 && words.contains(&initials))
+    })
+}
+
+/// A project is mentioned by two parts of its slug, e.g. "cnh mobile", or
+/// the whole slug if it only has one.
+fn mentioned_project<'a>(data: &'a Portfolio, words: &[String]) -> Option<&'a Project> {
+    data.projects.iter().find(|project| {
+        let parts: Vec<&str> = project.slug.split('-').collect();
+        let named = parts
+            .iter()
+            .filter(|part| words.iter().any(|word| word == *part))
+            .count();
+        named >= parts.len().min(2)
+    })
+}
+
+fn skills_answer(data: &Portfolio) -> Vec<(String, LineStyle)> {
+    let mut rows = Vec::new();
+    for (category, heading) in CATEGORIES {
+        let mut skills = text::skills_in(data, &[category]);
+        if skills.is_empty() {
+            continue;
+        }
+        skills.sort_by_key(|skill| std::cmp::Reverse(skill.level));
+        let names: Vec<&str> = skills.iter().map(|skill| skill.name.as_str()).collect();
+        let heading = i18n::t_or(&format!("skills.category.{}", category), heading);
+        rows.push((heading, names.join(", ")));
+    }
+    let width = rows
+        .iter()
+        .map(|(heading, _)| heading.chars().count())
+        .max()
+        .unwrap_or(0)
+        + 2;
+    let mut lines: Vec<(String, LineStyle)> = rows
+        .into_iter()
+        .map(|(heading, names)| {
+            (
+                format!("  {:<width$}{}", format!("{}:", heading), names),
+                LineStyle::Normal,
+            )
+        })
+        .collect();
+    let languages = text::skills_in(data, &["languages"]);
+    let rated = languages.iter().filter(|skill| skill.level.is_some());
+    if let Some(best) = rated.max_by_key(|skill| skill.level) {
+        lines.insert(
+            0,
+            (
+                format!("  {}", i18n::tr("ask.strongest", &[("skill", &best.name)])),
+                LineStyle::Accent,
+            ),
+        );
+    }
+    lines
+}
+
+fn skill_answer(data: &Portfolio, skill: &Skill) -> Vec<(String, LineStyle)> {
+    // Only what has been filled in, e.g. "Swift: Advanced, 6 yrs of experience."
+    let mut known = Vec::new();
+    if skill.level.is_some() {
+        known.push(skill.proficiency().to_string());
+    }
+    if let Some(years) = skill.years {
+        known.push(i18n::tr(
+            "ask.experience_years",
+            &[("years", &text::years(years))],
+        ));
+    }
+    let summary = if known.is_empty() {
+        format!("  {}", skill.name)
+    } else {
+        format!("  {}: {}.", skill.name, known.join(", "))
+    };
+    let mut lines = vec![(summary, LineStyle::Accent)];
+    let used_in: Vec<&str> = projects_using(data, skill)
+        .map(|project| project.name.as_str())
+        .collect();
+    if !used_in.is_empty() {
+        lines.push((
+            format!(
+                "  {}",
+                i18n::tr("ask.used_in", &[("projects", &used_in.join(", "))])
+            ),
+            LineStyle::Normal,
+        ));
+    }
+    lines
+}
+
+fn built_with_answer(data: &Portfolio, skill: &Skill) -> Vec<(String, LineStyle)> {
+    let projects: Vec<&Project> = projects_using(data, skill).collect();
+    if projects.is_empty() {
+        return vec![(
+            format!(
+                "  {}",
+                i18n::tr("ask.nothing_built_with", &[("skill", &skill.name)])
+            ),
+            LineStyle::Normal,
+        )];
+    }
+    let mut lines = vec![(
+        format!(
+            "  {}",
+            i18n::tr("ask.built_with", &[("skill", &skill.name)])
+        ),
+        LineStyle::Accent,
+    )];
+    lines.extend(projects.into_iter().map(|project| {
+        (
+            format!("  {} - {}", project.name, project.summary),
+            LineStyle::Normal,
+        )
+    }));
+    lines
+}
+
+fn projects_using<'a>(data: &'a Portfolio, skill: &'a Skill) -> impl Iterator<Item = &'a Project> {
+    data.projects.iter().filter(move |project| {
+        project
+            .tech
+            .iter()
+            .any(|tech| tech.eq_ignore_ascii_case(&skill.name))
+    })
+}
+
+fn experience_answer(data: &Portfolio) -> Vec<(String, LineStyle)> {
+    let today = this_month();
+    let mut lines = Vec::new();
+    let mut total = 0;
+    for job in &data.experience {
+        let months =
+            text::months_between(&job.start, job.end.as_deref().unwrap_or(&today)).unwrap_or(0);
+        total += months;
+        let key = match job.end {
+            None => "ask.job_now",
+            Some(_) => "ask.job_before",
+        };
+        let line = format!("  {}", job_line(key, job, months));
+        let style = if job.end.is_none() {
+            LineStyle::Accent
+        } else {
+            LineStyle::Normal
+        };
+        lines.push((line, style));
+    }
+    lines.push((
+        format!(
+            "  {}",
+            i18n::tr("ask.total", &[("duration", &text::duration(total))])
+        ),
+        LineStyle::Normal,
+    ));
+    lines
+}
+
+/// "Role at Company, for 2 yrs." in the words of the message `key`.
+fn job_line(key: &str, job: &Experience, months: u32) -> String {
+    i18n::tr(
+        key,
+        &[
+            ("role", &job.role),
+            ("company", &job.company),
+            ("duration", &text::duration(months)),
+        ],
+    )
+}
+
+fn job_answer(job: &Experience) -> Vec<(String, LineStyle)> {
+    let end = job.end.clone().unwrap_or_else(this_month);
+    let months = text::months_between(&job.start, &end).unwrap_or(0);
+    let mut lines = vec![
+        (
+            format!("  {}", job_line("ask.job", job, months)),
+            LineStyle::Accent,
+        ),
+        (format!("  {}", job.summary), LineStyle::Normal),
+    ];
+    lines.extend(
+        job.highlights
+            .iter()
+            .map(|highlight| (format!("  - {}", highlight), LineStyle::Normal)),
+    );
+    lines
+}
+
+fn projects_answer(data: &Portfolio) -> Vec<(String, LineStyle)> {
+    data.projects
+        .iter()
+        .map(|project| {
+            (
+                format!("  {} - {}", project.name, project.summary),
+                LineStyle::Normal,
+            )
+        })
+        .collect()
+}
+
+fn project_answer(project: &Project) -> Vec<(String, LineStyle)> {
+    vec![
+        (
+            format!("  {} ({})", project.name, project.status),
+            LineStyle::Accent,
+        ),
+        (format!("  {}", project.description), LineStyle::Normal),
+        (
+            format!(
+                "  {}",
+                i18n::tr("ask.tech", &[("tech", &project.tech.join(", "))])
+            ),
+            LineStyle::Normal,
+        ),
+    ]
+}
+
+fn contact_answer(data: &Portfolio) -> Vec<(String, LineStyle)> {
+    let mut lines: Vec<(String, LineStyle)> = data
+        .contact
+        .iter()
+        .map(|method| {
+            let line = format!("  {:<10}{}", format!("{}:", method.label), method.value);
+            match &method.url {
+                Some(url) => (line, LineStyle::Link(url.clone())),
+                None => (line, LineStyle::Normal),
+            }
+        })
+        .collect();
+    lines.push((
+        format!("  {}", i18n::t("ask.contact_write")),
+        LineStyle::Normal,
+    ));
+    lines
+}
+
+fn about_answer(data: &Portfolio) -> Vec<(String, LineStyle)> {
+    let profile = &data.profile;
+    vec![
+        (
+            format!("  {}, {}.", profile.name, profile.title),
+            LineStyle::Accent,
+        ),
+        (format!("  {}", profile.tagline), LineStyle::Normal),
+        (format!("  {}", profile.focus), LineStyle::Normal),
+    ]
+}
+
+fn resume_answer(_: &Portfolio) -> Vec<(String, LineStyle)> {
+    vec![(format!("  {}", i18n::t("ask.resume")), LineStyle::Normal)]
+}
+
+fn blog_answer(_: &Portfolio) -> Vec<(String, LineStyle)> {
+    let mut lines = vec![(
+        format!("  {}", i18n::t("ask.latest_posts")),
+        LineStyle::Accent,
+    )];
+    lines.extend(blog::posts().iter().take(3).map(|post| {
+        (
+            format!("  {}  {}", post.date, post.title),
+            LineStyle::Normal,
+        )
+    }));
+    lines
+}
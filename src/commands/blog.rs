@@ -0,0 +1,208 @@
+use std::collections::BTreeMap;
+
+use crate::commands::{CommandOutput, LineStyle};
+use crate::filesystem::model::{VirtualFs, HOME};
+use crate::i18n;
+use crate::parser::markdown;
+use crate::portfolio::blog::{self, Post};
+
+const USAGE: &str = "blog [ls [tag] | read <slug> | tags | search <query> | rss]";
+
+pub fn blog(fs: &VirtualFs, args: &[String]) -> CommandOutput {
+    let posts = load(fs);
+    match args.first().map(String::as_str) {
+        None | Some("ls") => list(&posts, args.get(1)),
+        Some("read") => match args.get(1) {
+            Some(slug) => read(&posts, slug),
+            None => error(format!("blog: {}", i18n::t("blog.which_post"))),
+        },
+        Some("tags") => tags(&posts),
+        Some("search") if args.len() > 1 => search(&posts, &args[1..].join(" ")),
+        Some("rss") => rss(),
+        _ => error(i18n::tr("shell.usage", &[("usage", USAGE)])),
+    }
+}
+
+/// The posts in ~/blog as they are now, including any the visitor wrote.
+fn load(fs: &VirtualFs) -> Vec<Post> {
+    let dir = format!("{}/blog", HOME);
+    let mut posts: Vec<Post> = fs
+        .ls(&dir, false)
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|(name, is_dir)| !is_dir && name.ends_with(".md"))
+        .filter_map(|(name, _)| {
+            let source = fs.cat(&format!("{}/{}", dir, name))?;
+            Some(blog::parse(name.trim_end_matches(".md"), &source))
+        })
+        .collect();
+    posts.sort_by(|a, b| b.date.cmp(&a.date));
+    posts
+}
+
+fn list(posts: &[Post], tag: Option<&String>) -> CommandOutput {
+    let tag = tag.map(|t| t.trim_start_matches('#').to_lowercase());
+    let listed: Vec<&Post> = posts
+        .iter()
+        .filter(|post| tag.as_ref().is_none_or(|tag| post.tags.contains(tag)))
+        .collect();
+    if listed.is_empty() {
+        let message = match tag {
+            Some(tag) => i18n::tr("blog.no_tagged", &[("tag", &tag)]),
+            None => i18n::t("blog.no_posts"),
+        };
+        return error(format!("blog: {}", message));
+    }
+
+    let mut lines = vec![(String::new(), LineStyle::Normal)];
+    for post in listed {
+        lines.push((
+            format!("  {:<10}  {}", post.date, post.title),
+            LineStyle::Accent,
+        ));
+        lines.push((format!("{:14}{}", "", post.blurb()), LineStyle::Normal));
+        lines.push((
+            format!("{:14}{}  {}", "", post.slug, hashtags(&post.tags)),
+            LineStyle::Muted,
+        ));
+        lines.push((String::new(), LineStyle::Normal));
+    }
+    lines.push((i18n::t("blog.read_hint"), LineStyle::Muted));
+    lines.push((String::new(), LineStyle::Normal));
+    output(lines)
+}
+
+fn read(posts: &[Post], slug: &str) -> CommandOutput {
+    let slug = slug.trim_end_matches(".md");
+    let Some(post) = posts.iter().find(|post| post.slug == slug) else {
+        return error(format!(
+            "blog: {}",
+            i18n::tr("blog.no_post", &[("slug", slug)])
+        ));
+    };
+
+    let mut lines = vec![
+        (String::new(), LineStyle::Normal),
+        (post.title.clone(), LineStyle::Accent),
+        ("═".repeat(post.title.chars().count()), LineStyle::Accent),
+        (
+            format!("{}  {}", post.date, hashtags(&post.tags))
+                .trim()
+                .to_string(),
+            LineStyle::Muted,
+        ),
+        (String::new(), LineStyle::Normal),
+    ];
+    lines.extend(markdown::render(&post.body));
+    lines.push((String::new(), LineStyle::Normal));
+    output(lines)
+}
+
+fn tags(posts: &[Post]) -> CommandOutput {
+    let mut counts: BTreeMap<&str, usize> = BTreeMap::new();
+    for tag in posts.iter().flat_map(|post| &post.tags) {
+        *counts.entry(tag).or_default() += 1;
+    }
+    if counts.is_empty() {
+        return error(format!("blog: {}", i18n::t("blog.no_tags")));
+    }
+
+    let mut counts: Vec<(&str, usize)> = counts.into_iter().collect();
+    counts.sort_by_key(|(_, count)| std::cmp::Reverse(*count));
+    let mut lines = vec![(String::new(), LineStyle::Normal)];
+    for (tag, count) in counts {
+        let key = if count == 1 {
+            "blog.post_count_one"
+        } else {
+            "blog.post_count"
+        };
+        lines.push((
+            format!(
+                "  {:<16}{}",
+                format!("#{}", tag),
+                i18n::tr(key, &[("count", &count.to_string())])
+            ),
+            LineStyle::Normal,
+        ));
+    }
+    lines.push((String::new(), LineStyle::Normal));
+    lines.push((i18n::t("blog.tags_hint"), LineStyle::Muted));
+    lines.push((String::new(), LineStyle::Normal));
+    output(lines)
+}
+
+fn search(posts: &[Post], query: &str) -> CommandOutput {
+    let needle = query.to_lowercase();
+    let mut lines = vec![(String::new(), LineStyle::Normal)];
+    for post in posts {
+        let in_title = post.title.to_lowercase().contains(&needle);
+        let in_tags = post.tags.iter().any(|tag| tag.contains(&needle));
+        let snippet = post
+            .body
+            .lines()
+            .map(str::trim)
+            .find(|line| line.to_lowercase().contains(&needle));
+        if !in_title && !in_tags && snippet.is_none() {
+            continue;
+        }
+
+        lines.push((
+            format!("  {}  ({})", post.title, post.slug),
+            LineStyle::Accent,
+        ));
+        let context = match snippet {
+            Some(line) => line.replace(['*', '`'], ""),
+            None => post.blurb(),
+        };
+        lines.push((format!("    {}", context), LineStyle::Normal));
+        lines.push((String::new(), LineStyle::Normal));
+    }
+
+    if lines.len() == 1 {
+        return error(format!(
+            "blog: {}",
+            i18n::tr("blog.no_mentions", &[("query", query)])
+        ));
+    }
+    lines.push((i18n::t("blog.read_hint"), LineStyle::Muted));
+    lines.push((String::new(), LineStyle::Normal));
+    output(lines)
+}
+
+/// Where to subscribe, and the feed as published with the site.
+fn rss() -> CommandOutput {
+    let origin = web_sys::window()
+        .and_then(|window| window.location().origin().ok())
+        .unwrap_or_default();
+    let url = format!("{}/rss.xml", origin);
+
+    let mut lines = vec![
+        (String::new(), LineStyle::Normal),
+        (
+            format!("  {}", i18n::tr("blog.subscribe", &[("url", &url)])),
+            LineStyle::Link(url),
+        ),
+        (String::new(), LineStyle::Normal),
+    ];
+    lines.extend(
+        blog::rss(blog::posts(), &origin)
+            .lines()
+            .map(|line| (line.to_string(), LineStyle::Code)),
+    );
+    CommandOutput::new(lines, false, false)
+}
+
+fn hashtags(tags: &[String]) -> String {
+    tags.iter()
+        .map(|tag| format!("#{}", tag))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn output(lines: Vec<(String, LineStyle)>) -> CommandOutput {
+    CommandOutput::new(lines, false, true)
+}
+
+fn error(message: String) -> CommandOutput {
+    CommandOutput::new(vec![(message, LineStyle::Error)], false, false)
+}
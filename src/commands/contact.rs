@@ -0,0 +1,285 @@
+use crate::commands::prompt::{self, Field, PromptSession, Purpose};
+use crate::commands::{download, links, CommandOutput, LineStyle};
+use crate::i18n;
+use crate::portfolio::{portfolio, vcard};
+
+/// Questions asked by `contact --write`, in order.
+const FIELDS: &[Field] = &[
+    Field {
+        label: "contact.field.name",
+        multiline: false,
+        validate: prompt::required,
+    },
+    Field {
+        label: "contact.field.email",
+        multiline: false,
+        validate: valid_email,
+    },
+    Field {
+        label: "contact.field.subject",
+        multiline: false,
+        validate: prompt::required,
+    },
+    Field {
+        label: "contact.field.message",
+        multiline: true,
+        validate: prompt::required,
+    },
+];
+
+const ACTIONS: &[(&str, &str)] = &[("m", "contact.action.mail"), ("c", "contact.action.copy")];
+
+/// A message written with `contact --write`.
+const EMAIL: Purpose = Purpose {
+    fields: FIELDS,
+    preview,
+    actions: ACTIONS,
+    finish,
+};
+
+pub fn contact(args: &[String]) -> CommandOutput {
+    if args.iter().any(|a| a == "--write") {
+        return write();
+    }
+
+    let mut lines = vec![(String::new(), LineStyle::Normal)];
+    for method in &portfolio().contact {
+        let line = format!("  {:<10}{}", format!("{}:", method.label), method.value);
+        let style = match &method.url {
+            Some(url) => LineStyle::Link(url.clone()),
+            None => LineStyle::Normal,
+        };
+        lines.push((line, style));
+    }
+    lines.push((String::new(), LineStyle::Normal));
+    lines.push((i18n::t("contact.write_hint"), LineStyle::Muted));
+    lines.push((String::new(), LineStyle::Normal));
+    CommandOutput::new(lines, false, true)
+}
+
+fn write() -> CommandOutput {
+    let Some(to) = recipient() else {
+        return CommandOutput::new(
+            vec![(
+                format!("contact: {}", i18n::t("contact.no_recipient")),
+                LineStyle::Error,
+            )],
+            false,
+            false,
+        );
+    };
+
+    let mut lines = vec![
+        (String::new(), LineStyle::Normal),
+        (
+            format!("  {}", i18n::tr("contact.writing_to", &[("email", to)])),
+            LineStyle::Accent,
+        ),
+    ];
+    lines.extend(
+        i18n::t("contact.instructions")
+            .lines()
+            .map(|line| (format!("  {}", line), LineStyle::Muted)),
+    );
+    lines.push((String::new(), LineStyle::Normal));
+    CommandOutput::new(lines, false, false).with_prompt(PromptSession::new(EMAIL))
+}
+
+/// A business card, and with `--download` the same details as a vCard.
+pub fn card(args: &[String]) -> CommandOutput {
+    let data = portfolio();
+    let profile = &data.profile;
+
+    if args.iter().any(|a| a == "--download") {
+        let filename = format!("{}.vcf", profile.name.to_lowercase().replace(' ', "-"));
+        let bytes = vcard::vcard(data).into_bytes();
+        return match download::trigger_download(&filename, download::mime_type(&filename), &bytes) {
+            Ok(()) => CommandOutput::new(
+                vec![(
+                    format!("  {}", i18n::tr("card.downloading", &[("file", &filename)])),
+                    LineStyle::Accent,
+                )],
+                false,
+                false,
+            ),
+            Err(err) => CommandOutput::new(
+                vec![(
+                    format!("card: {}", i18n::tr("download.failed", &[("reason", &err)])),
+                    LineStyle::Error,
+                )],
+                false,
+                false,
+            ),
+        };
+    }
+
+    let mut rows = vec![
+        (profile.name.clone(), LineStyle::Accent),
+        (profile.title.clone(), LineStyle::Normal),
+    ];
+    if let Some(job) = data.experience.iter().find(|job| job.end.is_none()) {
+        rows.push((job.company.clone(), LineStyle::Normal));
+    }
+    rows.push((String::new(), LineStyle::Normal));
+    for method in &data.contact {
+        rows.push((
+            format!("{:<10}{}", method.label, method.value),
+            LineStyle::Normal,
+        ));
+    }
+
+    let width = rows
+        .iter()
+        .map(|(text, _)| text.chars().count())
+        .max()
+        .unwrap_or(0);
+    let mut lines = vec![
+        (String::new(), LineStyle::Normal),
+        (format!("  ╭{}╮", "─".repeat(width + 4)), LineStyle::Muted),
+    ];
+    for (text, style) in rows {
+        let padding = width - text.chars().count();
+        lines.push((format!("  │  {}{}  │", text, " ".repeat(padding)), style));
+    }
+    lines.push((format!("  ╰{}╯", "─".repeat(width + 4)), LineStyle::Muted));
+    lines.push((String::new(), LineStyle::Normal));
+    lines.push((i18n::t("card.hint"), LineStyle::Muted));
+    lines.push((String::new(), LineStyle::Normal));
+    CommandOutput::new(lines, false, true)
+}
+
+/// The first mailto: contact method, without its scheme.
+pub fn recipient() -> Option<&'static str> {
+    portfolio()
+        .contact
+        .iter()
+        .filter_map(|method| method.url.as_deref())
+        .find_map(|url| url.strip_prefix("mailto:"))
+}
+
+pub fn valid_email(answer: &str) -> Result<(), String> {
+    if links::is_email(answer) {
+        Ok(())
+    } else {
+        Err(i18n::tr("contact.bad_email", &[("answer", answer)]))
+    }
+}
+
+fn preview(answers: &[String]) -> Vec<(String, LineStyle)> {
+    let [name, email, subject, message] = answers else {
+        return vec![];
+    };
+    let [to_label, from_label, subject_label] = headers();
+    let width = [&to_label, &from_label, &subject_label]
+        .iter()
+        .map(|label| label.chars().count())
+        .max()
+        .unwrap_or(0)
+        + 2;
+    let header =
+        |label: &str, value: String| format!("  {:<width$}{}", format!("{}:", label), value);
+    let mut lines = vec![
+        (String::new(), LineStyle::Normal),
+        (
+            header(&to_label, recipient().unwrap_or_default().to_string()),
+            LineStyle::Normal,
+        ),
+        (
+            header(&from_label, format!("{} <{}>", name, email)),
+            LineStyle::Normal,
+        ),
+        (header(&subject_label, subject.clone()), LineStyle::Accent),
+        (String::new(), LineStyle::Normal),
+    ];
+    lines.extend(
+        message
+            .lines()
+            .map(|line| (format!("  {}", line), LineStyle::Normal)),
+    );
+    lines
+}
+
+fn finish(action: &str, answers: &[String]) -> CommandOutput {
+    let ([name, email, subject, message], Some(to)) = (answers, recipient()) else {
+        return CommandOutput::new(vec![], false, false);
+    };
+    let body = format!("{}\n\n{} <{}>", message, name, email);
+
+    if action == "c" {
+        let [to_label, from_label, subject_label] = headers();
+        let text = format!(
+            "{}: {}\n{}: {} <{}>\n{}: {}\n\n{}",
+            to_label, to, from_label, name, email, subject_label, subject, body
+        );
+        // The write finishes, or is refused, after this command has already
+        // printed, so all we can report is that it was asked for. Insecure
+        // pages have no clipboard at all.
+        let requested = web_sys::window()
+            .map(|window| window.navigator())
+            .filter(|navigator| {
+                js_sys::Reflect::get(navigator, &"clipboard".into())
+                    .is_ok_and(|clipboard| !clipboard.is_undefined())
+            })
+            .map(|navigator| navigator.clipboard().write_text(&text))
+            .is_some();
+        let lines = if requested {
+            vec![
+                (
+                    format!("  {}", i18n::tr("contact.copied", &[("email", to)])),
+                    LineStyle::Accent,
+                ),
+                (
+                    format!("  {}", i18n::t("contact.copy_hint")),
+                    LineStyle::Muted,
+                ),
+            ]
+        } else {
+            vec![(
+                format!("  {}", i18n::t("contact.no_clipboard")),
+                LineStyle::Error,
+            )]
+        };
+        return CommandOutput::new(lines, false, false);
+    }
+
+    let href = format!(
+        "mailto:{}?subject={}&body={}",
+        to,
+        percent_encode(subject),
+        percent_encode(&body)
+    );
+    if let Some(window) = web_sys::window() {
+        let _ = window.location().set_href(&href);
+    }
+    CommandOutput::new(
+        vec![
+            (
+                format!("  {}", i18n::t("contact.opening_mail")),
+                LineStyle::Accent,
+            ),
+            (
+                format!("  {}", i18n::t("contact.mail_fallback")),
+                LineStyle::Link(href),
+            ),
+        ],
+        false,
+        false,
+    )
+}
+
+/// The To, From and Subject labels of a message.
+fn headers() -> [String; 3] {
+    ["contact.to", "contact.from", "contact.subject"].map(i18n::t)
+}
+
+/// Escape everything but unreserved characters, for a mailto: query.
+fn percent_encode(text: &str) -> String {
+    text.bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                (b as char).to_string()
+            }
+            _ => format!("%{:02X}", b),
+        })
+        .collect()
+}
@@ -0,0 +1,255 @@
+use crate::commands::{CommandOutput, LineStyle};
+use crate::filesystem::builder;
+use crate::filesystem::model::VirtualFs;
+use crate::i18n;
+
+const CONTEXT: usize = 3;
+
+#[derive(Clone, Copy, PartialEq)]
+enum Op {
+    Equal(usize, usize),
+    Delete(usize),
+    Insert(usize),
+}
+
+/// Line-level edit script from `a` to `b` via longest common subsequence.
+fn edit_script(a: &[&str], b: &[&str]) -> Vec<Op> {
+    let (n, m) = (a.len(), b.len());
+    // lcs[i][j] = LCS length of a[i..] and b[j..]
+    let mut lcs = vec![vec![0u32; m + 1]; n + 1];
+    for i in (0..n).rev() {
+        for j in (0..m).rev() {
+            lcs[i][j] = if a[i] == b[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let mut ops = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < n || j < m {
+        if i < n && j < m && a[i] == b[j] {
+            ops.push(Op::Equal(i, j));
+            i += 1;
+            j += 1;
+        } else if i < n && (j == m || lcs[i + 1][j] >= lcs[i][j + 1]) {
+            // Prefer deletions first so changes read as "-old" then "+new"
+            ops.push(Op::Delete(i));
+            i += 1;
+        } else {
+            ops.push(Op::Insert(j));
+            j += 1;
+        }
+    }
+    ops
+}
+
+/// Position in each file just before `ops[index]`.
+fn position(ops: &[Op], index: usize) -> (usize, usize) {
+    ops[..index].iter().fold((0, 0), |(a, b), op| match op {
+        Op::Equal(..) => (a + 1, b + 1),
+        Op::Delete(_) => (a + 1, b),
+        Op::Insert(_) => (a, b + 1),
+    })
+}
+
+/// Ranges of `ops` indices that contain changes, padded with `context`
+/// equal lines and merged when they overlap.
+fn hunks(ops: &[Op], context: usize) -> Vec<(usize, usize)> {
+    let mut ranges: Vec<(usize, usize)> = Vec::new();
+    for (i, op) in ops.iter().enumerate() {
+        if matches!(op, Op::Equal(..)) {
+            continue;
+        }
+        let start = i.saturating_sub(context);
+        let end = (i + 1 + context).min(ops.len());
+        match ranges.last_mut() {
+            Some(last) if start <= last.1 => last.1 = end,
+            _ => ranges.push((start, end)),
+        }
+    }
+    ranges
+}
+
+fn range_label(start: usize, count: usize) -> String {
+    match count {
+        0 => format!("{}", start),
+        1 => format!("{}", start + 1),
+        _ => format!("{},{}", start + 1, start + count),
+    }
+}
+
+fn unified(a: &[&str], b: &[&str], a_name: &str, b_name: &str) -> Vec<(String, LineStyle)> {
+    let ops = edit_script(a, b);
+    let mut lines = vec![
+        (format!("--- {}", a_name), LineStyle::Removed),
+        (format!("+++ {}", b_name), LineStyle::Added),
+    ];
+
+    for (start, end) in hunks(&ops, CONTEXT) {
+        let (a_start, b_start) = position(&ops, start);
+        let hunk = &ops[start..end];
+        let a_count = hunk
+            .iter()
+            .filter(|op| !matches!(op, Op::Insert(_)))
+            .count();
+        let b_count = hunk
+            .iter()
+            .filter(|op| !matches!(op, Op::Delete(_)))
+            .count();
+
+        // Empty sides are reported as starting at the line before
+        let a_line = if a_count == 0 { a_start } else { a_start + 1 };
+        let b_line = if b_count == 0 { b_start } else { b_start + 1 };
+        lines.push((
+            format!("@@ -{},{} +{},{} @@", a_line, a_count, b_line, b_count),
+            LineStyle::Accent,
+        ));
+
+        for op in hunk {
+            lines.push(match *op {
+                Op::Equal(i, _) => (format!(" {}", a[i]), LineStyle::Normal),
+                Op::Delete(i) => (format!("-{}", a[i]), LineStyle::Removed),
+                Op::Insert(j) => (format!("+{}", b[j]), LineStyle::Added),
+            });
+        }
+    }
+    lines
+}
+
+fn normal(a: &[&str], b: &[&str]) -> Vec<(String, LineStyle)> {
+    let ops = edit_script(a, b);
+    let mut lines = Vec::new();
+
+    for (start, end) in hunks(&ops, 0) {
+        let (a_start, b_start) = position(&ops, start);
+        let hunk = &ops[start..end];
+        let deleted: Vec<usize> = hunk
+            .iter()
+            .filter_map(|op| match op {
+                Op::Delete(i) => Some(*i),
+                _ => None,
+            })
+            .collect();
+        let inserted: Vec<usize> = hunk
+            .iter()
+            .filter_map(|op| match op {
+                Op::Insert(j) => Some(*j),
+                _ => None,
+            })
+            .collect();
+
+        let kind = match (deleted.is_empty(), inserted.is_empty()) {
+            (true, _) => 'a',
+            (_, true) => 'd',
+            _ => 'c',
+        };
+        lines.push((
+            format!(
+                "{}{}{}",
+                range_label(a_start, deleted.len()),
+                kind,
+                range_label(b_start, inserted.len())
+            ),
+            LineStyle::Accent,
+        ));
+
+        for i in &deleted {
+            lines.push((format!("< {}", a[*i]), LineStyle::Removed));
+        }
+        if kind == 'c' {
+            lines.push(("---".to_string(), LineStyle::Muted));
+        }
+        for j in &inserted {
+            lines.push((format!("> {}", b[*j]), LineStyle::Added));
+        }
+    }
+    lines
+}
+
+pub fn diff(fs: &VirtualFs, cwd: &str, args: &[String]) -> CommandOutput {
+    let unified_format = args.iter().any(|a| a == "-u");
+    let operands: Vec<&String> = args.iter().filter(|a| !a.starts_with('-')).collect();
+
+    // With a single file, compare against the version that shipped with the site
+    let shipped;
+    let (a_fs, a_arg, b_arg) = match operands.as_slice() {
+        [file] => {
+            shipped = builder::build_default_fs();
+            (&shipped, *file, *file)
+        }
+        [a, b] => (fs, *a, *b),
+        _ => {
+            return error(format!(
+                "diff: {}",
+                i18n::tr("shell.usage", &[("usage", "diff [-u] <file> [other]")])
+            ))
+        }
+    };
+
+    let a_path = a_fs.resolve_path(cwd, a_arg);
+    let b_path = fs.resolve_path(cwd, b_arg);
+
+    if a_fs.is_binary(&a_path) || fs.is_binary(&b_path) {
+        let same = a_fs.read_bytes(&a_path) == fs.read_bytes(&b_path);
+        return if same {
+            CommandOutput::new(vec![], false, false)
+        } else {
+            CommandOutput::new(
+                vec![(
+                    i18n::tr("diff.binary", &[("a", a_arg), ("b", b_arg)]),
+                    LineStyle::Normal,
+                )],
+                false,
+                false,
+            )
+        };
+    }
+
+    let a_text = if operands.len() == 1 && !a_fs.is_file(&a_path) {
+        // Created by the visitor, so everything is new
+        Some("".into())
+    } else {
+        a_fs.cat(&a_path)
+    };
+    let Some(a_text) = a_text else {
+        return error(format!("diff: {}: {}", a_arg, i18n::t("fs.no_such_file")));
+    };
+    let Some(b_text) = fs.cat(&b_path) else {
+        return error(format!("diff: {}: {}", b_arg, i18n::t("fs.no_such_file")));
+    };
+
+    let a_lines: Vec<&str> = a_text.lines().collect();
+    let b_lines: Vec<&str> = b_text.lines().collect();
+
+    if a_lines == b_lines {
+        let message = if operands.len() == 1 {
+            vec![(
+                format!("  {}", i18n::tr("diff.unchanged", &[("file", a_arg)])),
+                LineStyle::Muted,
+            )]
+        } else {
+            vec![]
+        };
+        return CommandOutput::new(message, false, false);
+    }
+
+    let lines = if unified_format {
+        let a_name = if operands.len() == 1 {
+            i18n::tr("diff.original", &[("file", a_arg)])
+        } else {
+            a_arg.to_string()
+        };
+        unified(&a_lines, &b_lines, &a_name, b_arg)
+    } else {
+        normal(&a_lines, &b_lines)
+    };
+
+    CommandOutput::new(lines, false, false)
+}
+
+fn error(message: String) -> CommandOutput {
+    CommandOutput::new(vec![(message, LineStyle::Error)], false, false)
+}
@@ -0,0 +1,215 @@
+use wasm_bindgen::JsCast;
+
+use crate::commands::{CommandOutput, LineStyle};
+use crate::filesystem::archive;
+use crate::filesystem::model::VirtualFs;
+use crate::i18n;
+
+/// Hand bytes to the browser as a file download via a Blob URL.
+pub fn trigger_download(filename: &str, mime: &str, bytes: &[u8]) -> Result<(), String> {
+    let array = js_sys::Uint8Array::from(bytes);
+    let parts = js_sys::Array::of1(&array);
+    let options = web_sys::BlobPropertyBag::new();
+    options.set_type(mime);
+
+    let blob = web_sys::Blob::new_with_u8_array_sequence_and_options(&parts, &options)
+        .map_err(|_| i18n::t("download.no_blob"))?;
+    let url = web_sys::Url::create_object_url_with_blob(&blob)
+        .map_err(|_| i18n::t("download.no_link"))?;
+
+    let document = web_sys::window()
+        .and_then(|w| w.document())
+        .ok_or_else(|| i18n::t("download.no_document"))?;
+    let anchor = document
+        .create_element("a")
+        .map_err(|_| i18n::t("download.no_link"))?
+        .dyn_into::<web_sys::HtmlAnchorElement>()
+        .map_err(|_| i18n::t("download.no_link"))?;
+    anchor.set_href(&url);
+    anchor.set_download(filename);
+    anchor.click();
+
+    // Give the browser a moment to start the download before freeing the URL
+    let revoke = wasm_bindgen::closure::Closure::once_into_js(move || {
+        let _ = web_sys::Url::revoke_object_url(&url);
+    });
+    if let Some(window) = web_sys::window() {
+        let _ = window
+            .set_timeout_with_callback_and_timeout_and_arguments_0(revoke.unchecked_ref(), 1000);
+    }
+    Ok(())
+}
+
+/// The MIME type for a file name, by its extension. Shared by downloads and
+/// inline images.
+pub fn mime_type(path: &str) -> &'static str {
+    match path.rsplit('.').next() {
+        Some("md") => "text/markdown",
+        Some("txt") => "text/plain",
+        Some("html") => "text/html",
+        Some("pdf") => "application/pdf",
+        Some("png") => "image/png",
+        Some("jpg" | "jpeg") => "image/jpeg",
+        Some("gif") => "image/gif",
+        Some("svg") => "image/svg+xml",
+        Some("gz" | "tgz") => "application/gzip",
+        Some("tar") => "application/x-tar",
+        Some("zip") => "application/zip",
+        Some("vcf") => "text/vcard",
+        Some("ics") => "text/calendar",
+        _ => "application/octet-stream",
+    }
+}
+
+pub fn download(fs: &VirtualFs, cwd: &str, args: &[String]) -> CommandOutput {
+    let Some(target) = args.first() else {
+        return error(format!("download: {}", i18n::t("fs.missing_file_operand")));
+    };
+
+    let resolved = fs.resolve_path(cwd, target);
+    if fs.is_dir(&resolved) {
+        let hint = i18n::tr(
+            "download.try_zip",
+            &[("name", basename(&resolved)), ("target", target)],
+        );
+        return error(format!(
+            "download: {}: {} ({})",
+            target,
+            i18n::t("fs.is_a_directory"),
+            hint
+        ));
+    }
+
+    let Some(bytes) = fs.read_bytes(&resolved) else {
+        return error(format!(
+            "download: {}: {}",
+            target,
+            i18n::t("fs.no_such_file")
+        ));
+    };
+
+    let filename = basename(&resolved);
+    finish(
+        trigger_download(filename, mime_type(filename), &bytes),
+        i18n::tr(
+            "download.started_size",
+            &[("file", filename), ("size", &human_size(bytes.len()))],
+        ),
+    )
+}
+
+pub fn tar(fs: &VirtualFs, cwd: &str, args: &[String]) -> CommandOutput {
+    let usage = format!(
+        "tar: {}",
+        i18n::tr(
+            "shell.usage",
+            &[("usage", "tar -czf <archive.tar.gz> <path>")]
+        )
+    );
+    let Some(flags) = args.first() else {
+        return error(usage);
+    };
+    let flags = flags.trim_start_matches('-');
+    if !flags.contains('c') || !flags.contains('f') {
+        return error(format!("tar: {}", i18n::t("download.tar_create_only")));
+    }
+    let (Some(archive_name), Some(source)) = (args.get(1), args.get(2)) else {
+        return error(usage);
+    };
+
+    let resolved = fs.resolve_path(cwd, source);
+    let entries = fs.walk(&resolved);
+    if entries.is_empty() {
+        return error(format!("tar: {}: {}", source, i18n::t("fs.not_found")));
+    }
+
+    let now = js_sys::Date::new_0();
+    let bytes = archive::tar(
+        &entries,
+        (now.get_time() / 1000.0) as u64,
+        flags.contains('z'),
+    );
+    packed(archive_name, &entries, &bytes)
+}
+
+pub fn zip(fs: &VirtualFs, cwd: &str, args: &[String]) -> CommandOutput {
+    let recursive = args.iter().any(|a| a == "-r");
+    let operands: Vec<&String> = args.iter().filter(|a| !a.starts_with('-')).collect();
+    let (Some(archive_name), Some(source)) = (operands.first(), operands.get(1)) else {
+        return error(format!(
+            "zip: {}",
+            i18n::tr("shell.usage", &[("usage", "zip -r <archive.zip> <path>")])
+        ));
+    };
+
+    let resolved = fs.resolve_path(cwd, source);
+    if fs.is_dir(&resolved) && !recursive {
+        return error(format!(
+            "zip: {}: {} ({})",
+            source,
+            i18n::t("fs.is_a_directory"),
+            i18n::t("download.zip_use_r")
+        ));
+    }
+
+    let entries = fs.walk(&resolved);
+    if entries.is_empty() {
+        return error(format!("zip: {}: {}", source, i18n::t("fs.not_found")));
+    }
+
+    let now = js_sys::Date::new_0();
+    let dos_time =
+        ((now.get_hours() << 11) | (now.get_minutes() << 5) | (now.get_seconds() / 2)) as u16;
+    let dos_date = (((now.get_full_year().saturating_sub(1980)) << 9)
+        | ((now.get_month() + 1) << 5)
+        | now.get_date()) as u16;
+
+    let bytes = archive::zip(&entries, dos_time, dos_date);
+    packed(archive_name, &entries, &bytes)
+}
+
+fn packed(archive_name: &str, entries: &[archive::Entry], bytes: &[u8]) -> CommandOutput {
+    let filename = basename(archive_name);
+    let files = entries.iter().filter(|(_, data)| data.is_some()).count();
+    finish(
+        trigger_download(filename, mime_type(filename), bytes),
+        i18n::tr(
+            "download.packed",
+            &[
+                ("count", &files.to_string()),
+                ("file", filename),
+                ("size", &human_size(bytes.len())),
+            ],
+        ),
+    )
+}
+
+fn finish(result: Result<(), String>, message: String) -> CommandOutput {
+    match result {
+        Ok(()) => CommandOutput::new(
+            vec![(format!("  {}", message), LineStyle::Accent)],
+            false,
+            false,
+        ),
+        Err(err) => error(format!(
+            "  {}",
+            i18n::tr("download.failed", &[("reason", &err)])
+        )),
+    }
+}
+
+fn basename(path: &str) -> &str {
+    path.rsplit('/').next().unwrap_or(path)
+}
+
+fn human_size(bytes: usize) -> String {
+    if bytes < 1024 {
+        format!("{} B", bytes)
+    } else {
+        format!("{:.1} KB", bytes as f64 / 1024.0)
+    }
+}
+
+fn error(message: String) -> CommandOutput {
+    CommandOutput::new(vec![(message, LineStyle::Error)], false, false)
+}
@@ -1,4 +1,5 @@
 use crate::commands::{CommandOutput, LineStyle};
+use crate::portfolio::portfolio;
 
 pub fn sudo(args: &[String]) -> CommandOutput {
     let _cmd_text = if args.is_empty() {
@@ -21,11 +22,13 @@ pub fn sudo(args: &[String]) -> CommandOutput {
         clear_screen: false,
         typewriter: true,
         start_game: None,
+        full_screen: None,
+        prompt: None,
     }
 }
 
 pub fn rm_rf() -> CommandOutput {
-    let mut lines = vec![
+    let lines = vec![
         ("  Deleting everything...".to_string(), LineStyle::Error),
         (
             "  rm: /usr/bin/: Permission denied".to_string(),
@@ -55,37 +58,63 @@ pub fn rm_rf() -> CommandOutput {
         clear_screen: false,
         typewriter: true,
         start_game: None,
+        full_screen: None,
+        prompt: None,
     }
 }
 
 pub fn neofetch() -> CommandOutput {
-    let ascii = vec![
-        ("        _______        ", LineStyle::Accent),
-        ("       /       \\       ", LineStyle::Accent),
-        ("      / PRUTHVI \\      ", LineStyle::Accent),
-        ("     /  PARNE    \\     ", LineStyle::Accent),
-        ("    /  ________   \\    ", LineStyle::Accent),
-        ("   /  /        \\   \\   ", LineStyle::Accent),
-        ("  /  /  SENIOR  \\   \\  ", LineStyle::Accent),
-        (" /  /  SOFTWARE  \\   \\ ", LineStyle::Accent),
-        ("/  /  ENGINEER    \\   \\", LineStyle::Accent),
-        ("\\  \\              /   /", LineStyle::Accent),
-        (" \\  \\____________/   / ", LineStyle::Accent),
-        ("  \\________________/  ", LineStyle::Accent),
-    ];
+    let data = portfolio();
+    let ascii = neofetch_art(&data.profile.name, &data.profile.title);
+    let blank_art = " ".repeat(ascii[0].chars().count());
+
+    let role = match data.experience.iter().find(|job| job.end.is_none()) {
+        Some(job) => format!("{} @ {}", data.profile.title, job.company),
+        None => data.profile.title.clone(),
+    };
+    let top_skills: Vec<&str> = data
+        .skills
+        .iter()
+        .filter(|skill| skill.category == "languages")
+        .take(3)
+        .map(|skill| skill.name.as_str())
+        .collect();
 
-    let info = vec![
-        ("", LineStyle::Normal),
-        ("  visitor@portfolio", LineStyle::Accent),
-        ("  -------------------", LineStyle::Muted),
-        ("  OS:       WebAssembly/Browser", LineStyle::Normal),
-        ("  Host:     GitHub Pages", LineStyle::Normal),
-        ("  Shell:    terminal-portfolio 0.1.0", LineStyle::Normal),
-        ("  Runtime:  Rust + Leptos + WASM", LineStyle::Normal),
-        ("  Theme:    Claude Orange on Dark", LineStyle::Normal),
-        ("  CPU:      Your Browser's JS Engine", LineStyle::Normal),
-        ("  Memory:   A few MB of WASM", LineStyle::Normal),
-        ("", LineStyle::Normal),
+    let info = [
+        (String::new(), LineStyle::Normal),
+        ("  visitor@portfolio".to_string(), LineStyle::Accent),
+        ("  -------------------".to_string(), LineStyle::Muted),
+        (
+            format!("  Name:     {}", data.profile.name),
+            LineStyle::Normal,
+        ),
+        (format!("  Role:     {}", role), LineStyle::Normal),
+        (
+            format!("  Skills:   {}", top_skills.join(", ")),
+            LineStyle::Normal,
+        ),
+        (
+            format!("  Projects: {}", data.projects.len()),
+            LineStyle::Normal,
+        ),
+        (
+            "  OS:       WebAssembly/Browser".to_string(),
+            LineStyle::Normal,
+        ),
+        ("  Host:     GitHub Pages".to_string(), LineStyle::Normal),
+        (
+            "  Shell:    terminal-portfolio 0.1.0".to_string(),
+            LineStyle::Normal,
+        ),
+        (
+            "  Runtime:  Rust + Leptos + WASM".to_string(),
+            LineStyle::Normal,
+        ),
+        (
+            "  Theme:    Claude Orange on Dark".to_string(),
+            LineStyle::Normal,
+        ),
+        (String::new(), LineStyle::Normal),
     ];
 
     let mut lines: Vec<(String, LineStyle)> = Vec::new();
@@ -94,16 +123,10 @@ pub fn neofetch() -> CommandOutput {
     // Interleave ASCII art and info
     let max_len = ascii.len().max(info.len());
     for i in 0..max_len {
-        let art_part = if i < ascii.len() {
-            ascii[i].0
-        } else {
-            "                        "
-        };
-        let info_part = if i < info.len() { info[i].0 } else { "" };
-        let style = if i < info.len() {
-            info[i].1.clone()
-        } else {
-            LineStyle::Normal
+        let art_part = ascii.get(i).unwrap_or(&blank_art);
+        let (info_part, style) = match info.get(i) {
+            Some((text, style)) => (text.as_str(), style.clone()),
+            None => ("", LineStyle::Normal),
         };
 
         lines.push((format!("  {} {}", art_part, info_part), style));
@@ -116,9 +139,33 @@ pub fn neofetch() -> CommandOutput {
         clear_screen: false,
         typewriter: true,
         start_game: None,
+        full_screen: None,
+        prompt: None,
     }
 }
 
+/// A framed name badge: the name and title, one uppercase word per row.
+fn neofetch_art(name: &str, title: &str) -> Vec<String> {
+    let name_words: Vec<String> = name.split_whitespace().map(str::to_uppercase).collect();
+    let title_words: Vec<String> = title.split_whitespace().map(str::to_uppercase).collect();
+    let width = name_words
+        .iter()
+        .chain(&title_words)
+        .map(|word| word.chars().count())
+        .max()
+        .unwrap_or(0)
+        + 8;
+
+    let centered = |word: &str| format!("│{:^width$}│", word, width = width);
+    let mut art = vec![format!("╭{}╮", "─".repeat(width)), centered("")];
+    art.extend(name_words.iter().map(|word| centered(word)));
+    art.push(centered(&"─".repeat(width - 6)));
+    art.extend(title_words.iter().map(|word| centered(word)));
+    art.push(centered(""));
+    art.push(format!("╰{}╯", "─".repeat(width)));
+    art
+}
+
 pub fn cowsay(args: &[String]) -> CommandOutput {
     let message = if args.is_empty() {
         "Moo! Type 'help' for commands.".to_string()
@@ -150,6 +197,8 @@ pub fn cowsay(args: &[String]) -> CommandOutput {
         clear_screen: false,
         typewriter: true,
         start_game: None,
+        full_screen: None,
+        prompt: None,
     }
 }
 
@@ -159,6 +208,8 @@ pub fn whoami() -> CommandOutput {
         clear_screen: false,
         typewriter: false,
         start_game: None,
+        full_screen: None,
+        prompt: None,
     }
 }
 
@@ -172,6 +223,8 @@ pub fn date() -> CommandOutput {
         clear_screen: false,
         typewriter: false,
         start_game: None,
+        full_screen: None,
+        prompt: None,
     }
 }
 
@@ -181,6 +234,8 @@ pub fn echo(args: &[String]) -> CommandOutput {
         clear_screen: false,
         typewriter: false,
         start_game: None,
+        full_screen: None,
+        prompt: None,
     }
 }
 
@@ -199,6 +254,8 @@ pub fn exit() -> CommandOutput {
         clear_screen: false,
         typewriter: true,
         start_game: None,
+        full_screen: None,
+        prompt: None,
     }
 }
 
@@ -217,5 +274,7 @@ pub fn history_cmd(history: &[String]) -> CommandOutput {
         clear_screen: false,
         typewriter: false,
         start_game: None,
+        full_screen: None,
+        prompt: None,
     }
 }
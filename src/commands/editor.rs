@@ -0,0 +1,242 @@
+use crate::commands::{CommandOutput, FullScreen, LineStyle};
+use crate::filesystem::model::VirtualFs;
+use crate::i18n;
+use crate::state::viewport;
+
+// Rows taken by the header, status bar and shortcut bar
+const CHROME_ROWS: usize = 4;
+
+pub enum EditorAction {
+    None,
+    Save,
+    Exit,
+}
+
+#[derive(Clone)]
+pub struct Editor {
+    pub path: String,
+    pub lines: Vec<String>,
+    pub row: usize,
+    pub col: usize,
+    pub scroll: usize,
+    pub height: usize,
+    pub dirty: bool,
+    pub status: String,
+    confirm_exit: bool,
+}
+
+impl Editor {
+    pub fn new(path: &str, content: Option<&str>) -> Self {
+        let (lines, status) = match content {
+            Some(text) => {
+                let lines: Vec<String> = text.lines().map(|l| l.to_string()).collect();
+                let status = i18n::tr("editor.read", &[("count", &lines.len().to_string())]);
+                (lines, status)
+            }
+            None => (Vec::new(), i18n::t("editor.new_file")),
+        };
+
+        Self {
+            path: path.to_string(),
+            lines: if lines.is_empty() {
+                vec![String::new()]
+            } else {
+                lines
+            },
+            row: 0,
+            col: 0,
+            scroll: 0,
+            height: viewport::rows().saturating_sub(CHROME_ROWS).max(5),
+            dirty: false,
+            status,
+            confirm_exit: false,
+        }
+    }
+
+    pub fn content(&self) -> String {
+        let mut text = self.lines.join("\n");
+        text.push('\n');
+        text
+    }
+
+    pub fn handle_key(&mut self, key: &str, ctrl: bool) -> EditorAction {
+        if ctrl {
+            return match key.to_lowercase().as_str() {
+                "s" | "o" => {
+                    self.confirm_exit = false;
+                    EditorAction::Save
+                }
+                "x" => {
+                    if self.dirty && !self.confirm_exit {
+                        self.confirm_exit = true;
+                        self.status = i18n::t("editor.unsaved");
+                        EditorAction::None
+                    } else {
+                        EditorAction::Exit
+                    }
+                }
+                _ => EditorAction::None,
+            };
+        }
+
+        self.confirm_exit = false;
+        match key {
+            "ArrowUp" => self.move_to(self.row.saturating_sub(1), self.col),
+            "ArrowDown" => self.move_to(self.row + 1, self.col),
+            "ArrowLeft" => {
+                if self.col > 0 {
+                    self.col -= 1;
+                } else if self.row > 0 {
+                    self.row -= 1;
+                    self.col = self.line_len(self.row);
+                }
+            }
+            "ArrowRight" => {
+                if self.col < self.line_len(self.row) {
+                    self.col += 1;
+                } else if self.row + 1 < self.lines.len() {
+                    self.row += 1;
+                    self.col = 0;
+                }
+            }
+            "Home" => self.col = 0,
+            "End" => self.col = self.line_len(self.row),
+            "PageUp" => self.move_to(self.row.saturating_sub(self.height), self.col),
+            "PageDown" => self.move_to(self.row + self.height, self.col),
+            "Enter" => {
+                let byte = self.byte_offset();
+                let rest = self.lines[self.row].split_off(byte);
+                self.lines.insert(self.row + 1, rest);
+                self.row += 1;
+                self.col = 0;
+                self.dirty = true;
+            }
+            "Backspace" => {
+                if self.col > 0 {
+                    self.col -= 1;
+                    let byte = self.byte_offset();
+                    self.lines[self.row].remove(byte);
+                    self.dirty = true;
+                } else if self.row > 0 {
+                    let line = self.lines.remove(self.row);
+                    self.row -= 1;
+                    self.col = self.line_len(self.row);
+                    self.lines[self.row].push_str(&line);
+                    self.dirty = true;
+                }
+            }
+            "Delete" => {
+                if self.col < self.line_len(self.row) {
+                    let byte = self.byte_offset();
+                    self.lines[self.row].remove(byte);
+                    self.dirty = true;
+                } else if self.row + 1 < self.lines.len() {
+                    let next = self.lines.remove(self.row + 1);
+                    self.lines[self.row].push_str(&next);
+                    self.dirty = true;
+                }
+            }
+            "Tab" => {
+                for _ in 0..4 {
+                    self.insert_char(' ');
+                }
+            }
+            other => {
+                let mut chars = other.chars();
+                if let (Some(ch), None) = (chars.next(), chars.next()) {
+                    self.insert_char(ch);
+                }
+            }
+        }
+
+        self.scroll_to_cursor();
+        EditorAction::None
+    }
+
+    /// Write the buffer back into the filesystem.
+    pub fn save(&mut self, fs: &mut VirtualFs) {
+        match fs.write_file(&self.path, &self.content()) {
+            Ok(()) => {
+                self.dirty = false;
+                self.status = i18n::tr("editor.wrote", &[("count", &self.lines.len().to_string())]);
+            }
+            Err(err) => {
+                self.status = i18n::tr(
+                    "editor.write_failed",
+                    &[("path", &self.path), ("reason", &err.message())],
+                )
+            }
+        }
+    }
+
+    fn insert_char(&mut self, ch: char) {
+        let byte = self.byte_offset();
+        self.lines[self.row].insert(byte, ch);
+        self.col += 1;
+        self.dirty = true;
+    }
+
+    fn move_to(&mut self, row: usize, col: usize) {
+        self.row = row.min(self.lines.len() - 1);
+        self.col = col.min(self.line_len(self.row));
+    }
+
+    fn line_len(&self, row: usize) -> usize {
+        self.lines[row].chars().count()
+    }
+
+    fn byte_offset(&self) -> usize {
+        let line = &self.lines[self.row];
+        line.char_indices()
+            .nth(self.col)
+            .map(|(i, _)| i)
+            .unwrap_or(line.len())
+    }
+
+    fn scroll_to_cursor(&mut self) {
+        if self.row < self.scroll {
+            self.scroll = self.row;
+        } else if self.row >= self.scroll + self.height {
+            self.scroll = self.row + 1 - self.height;
+        }
+    }
+}
+
+pub fn nano(fs: &VirtualFs, cwd: &str, args: &[String]) -> CommandOutput {
+    let Some(target) = args.first() else {
+        return CommandOutput::new(
+            vec![(
+                format!("nano: {}", i18n::t("fs.missing_file_operand")),
+                LineStyle::Error,
+            )],
+            false,
+            false,
+        );
+    };
+
+    let resolved = fs.resolve_path(cwd, target);
+    if fs.is_dir(&resolved) {
+        return CommandOutput::new(
+            vec![(
+                format!("nano: {}: {}", target, i18n::t("fs.is_a_directory")),
+                LineStyle::Error,
+            )],
+            false,
+            false,
+        );
+    }
+
+    if fs.is_binary(&resolved) {
+        return CommandOutput::new(
+            vec![(
+                format!("nano: {}: {}", target, i18n::t("editor.binary_file")),
+                LineStyle::Error,
+            )],
+            false,
+            false,
+        );
+    }
+
+    let editor = Editor::new(&resolved, fs.cat(&resolved).as_deref());
+    CommandOutput::new(vec![], false, false).with_full_screen(FullScreen::Editor(editor))
+}
@@ -1,59 +1,174 @@
-use crate::commands::{CommandOutput, LineStyle};
-use crate::filesystem::model::VirtualFs;
+use crate::commands::{image, CommandOutput, LineStyle};
+use crate::filesystem::builder;
+use crate::filesystem::model::{display_path, FsError, VirtualFs, HOME};
+use crate::i18n;
+use crate::parser::markdown;
+use crate::state::shell::Shell;
 
-pub fn pwd(cwd: &str) -> CommandOutput {
+pub fn pwd(shell: &Shell) -> CommandOutput {
     CommandOutput {
-        lines: vec![(cwd.to_string(), LineStyle::Normal)],
+        lines: vec![(shell.cwd.clone(), LineStyle::Normal)],
         clear_screen: false,
         typewriter: true,
         start_game: None,
+        full_screen: None,
+        prompt: None,
     }
 }
 
-pub fn cd(fs: &VirtualFs, cwd: &mut String, args: &[String]) -> CommandOutput {
+pub fn cd(fs: &VirtualFs, shell: &mut Shell, args: &[String]) -> CommandOutput {
     let target = match args.first() {
         Some(path) => path.as_str(),
         None => "~",
     };
 
-    let resolved = fs.resolve_path(cwd, target);
+    // `cd -` swaps back to the previous directory and prints where it landed
+    let (resolved, announce) = if target == "-" {
+        match &shell.oldpwd {
+            Some(previous) => (previous.clone(), true),
+            None => return error_output(format!("cd: {}", i18n::t("fs.oldpwd_unset"))),
+        }
+    } else {
+        (fs.resolve_path(&shell.cwd, target), false)
+    };
 
     if fs.is_dir(&resolved) {
-        *cwd = resolved;
+        let lines = if announce {
+            vec![(display_path(&resolved), LineStyle::Normal)]
+        } else {
+            vec![]
+        };
+        shell.change_dir(resolved);
         CommandOutput {
-            lines: vec![],
+            lines,
             clear_screen: false,
             typewriter: true,
             start_game: None,
+            full_screen: None,
+            prompt: None,
         }
     } else if fs.is_file(&resolved) {
         CommandOutput {
-            lines: vec![(format!("cd: not a directory: {}", target), LineStyle::Error)],
+            lines: vec![(
+                format!("cd: {}: {}", target, i18n::t("fs.not_a_directory")),
+                LineStyle::Error,
+            )],
             clear_screen: false,
             typewriter: true,
             start_game: None,
+            full_screen: None,
+            prompt: None,
         }
     } else {
         CommandOutput {
             lines: vec![(
-                format!("cd: no such directory: {}", target),
+                format!("cd: {}: {}", target, i18n::t("fs.no_such_directory")),
                 LineStyle::Error,
             )],
             clear_screen: false,
             typewriter: true,
             start_game: None,
+            full_screen: None,
+            prompt: None,
         }
     }
 }
 
+pub fn pushd(fs: &VirtualFs, shell: &mut Shell, args: &[String]) -> CommandOutput {
+    match args.first() {
+        // With no argument, swap the current directory with the top of the stack
+        None => {
+            let Some(top) = shell.dir_stack.first().cloned() else {
+                return error_output(format!("pushd: {}", i18n::t("fs.no_other_directory")));
+            };
+            if !fs.is_dir(&top) {
+                return error_output(format!(
+                    "pushd: {}: {}",
+                    display_path(&top),
+                    i18n::t("fs.no_such_directory")
+                ));
+            }
+            shell.dir_stack[0] = shell.cwd.clone();
+            shell.change_dir(top);
+        }
+        Some(target) => {
+            let resolved = fs.resolve_path(&shell.cwd, target);
+            if !fs.is_dir(&resolved) {
+                return error_output(format!(
+                    "pushd: {}: {}",
+                    target,
+                    i18n::t("fs.no_such_directory")
+                ));
+            }
+            shell.dir_stack.insert(0, shell.cwd.clone());
+            shell.change_dir(resolved);
+        }
+    }
+    dirs(shell, &[])
+}
+
+pub fn popd(fs: &VirtualFs, shell: &mut Shell) -> CommandOutput {
+    let Some(top) = shell.dir_stack.first() else {
+        return error_output(format!("popd: {}", i18n::t("fs.stack_empty")));
+    };
+    // The entry stays on the stack if it can't be entered
+    if !fs.is_dir(top) {
+        return error_output(format!(
+            "popd: {}: {}",
+            display_path(top),
+            i18n::t("fs.no_such_directory")
+        ));
+    }
+    let top = shell.dir_stack.remove(0);
+    shell.change_dir(top);
+    dirs(shell, &[])
+}
+
+pub fn dirs(shell: &mut Shell, args: &[String]) -> CommandOutput {
+    if args.iter().any(|a| a == "-c") {
+        shell.dir_stack.clear();
+        return CommandOutput::new(vec![], false, false);
+    }
+
+    let lines = if args.iter().any(|a| a == "-v") {
+        shell
+            .dirs()
+            .enumerate()
+            .map(|(i, dir)| {
+                (
+                    format!("{:>2}  {}", i, display_path(dir)),
+                    LineStyle::Normal,
+                )
+            })
+            .collect()
+    } else {
+        let joined: Vec<String> = shell.dirs().map(|dir| display_path(dir)).collect();
+        vec![(joined.join(" "), LineStyle::Normal)]
+    };
+
+    CommandOutput {
+        lines,
+        clear_screen: false,
+        typewriter: true,
+        start_game: None,
+        full_screen: None,
+        prompt: None,
+    }
+}
+
 pub fn ls(fs: &VirtualFs, cwd: &str, args: &[String]) -> CommandOutput {
-    let target = match args.first() {
+    let (show_hidden, paths) = split_all_flag(args);
+    let target = match paths.first() {
         Some(path) => fs.resolve_path(cwd, path),
         None => cwd.to_string(),
     };
 
-    match fs.ls(&target) {
-        Some(entries) => {
+    match fs.ls(&target, show_hidden) {
+        Some(mut entries) => {
+            if show_hidden {
+                entries.insert(0, ("..".to_string(), true));
+                entries.insert(0, (".".to_string(), true));
+            }
             let lines = entries
                 .iter()
                 .map(|(name, is_dir)| {
@@ -69,32 +184,48 @@ pub fn ls(fs: &VirtualFs, cwd: &str, args: &[String]) -> CommandOutput {
                 clear_screen: false,
                 typewriter: true,
                 start_game: None,
+                full_screen: None,
+                prompt: None,
             }
         }
         None => CommandOutput {
             lines: vec![(
                 format!(
-                    "ls: cannot access '{}': No such directory",
-                    args.first().unwrap_or(&target)
+                    "ls: {}",
+                    i18n::tr(
+                        "fs.cannot_access",
+                        &[
+                            ("path", paths.first().unwrap_or(&&target)),
+                            ("reason", &i18n::t("fs.no_such_directory")),
+                        ],
+                    )
                 ),
                 LineStyle::Error,
             )],
             clear_screen: false,
             typewriter: true,
             start_game: None,
+            full_screen: None,
+            prompt: None,
         },
     }
 }
 
 pub fn cat(fs: &VirtualFs, cwd: &str, args: &[String]) -> CommandOutput {
-    let target = match args.first() {
+    let raw = args.iter().any(|a| a == "--raw");
+    let target = match image::file_operand(args) {
         Some(path) => path.as_str(),
         None => {
             return CommandOutput {
-                lines: vec![("cat: missing file argument".to_string(), LineStyle::Error)],
+                lines: vec![(
+                    format!("cat: {}", i18n::t("fs.missing_file_operand")),
+                    LineStyle::Error,
+                )],
                 clear_screen: false,
                 typewriter: true,
                 start_game: None,
+                full_screen: None,
+                prompt: None,
             };
         }
     };
@@ -103,42 +234,168 @@ pub fn cat(fs: &VirtualFs, cwd: &str, args: &[String]) -> CommandOutput {
 
     if fs.is_dir(&resolved) {
         return CommandOutput {
-            lines: vec![(format!("cat: {}: Is a directory", target), LineStyle::Error)],
+            lines: vec![(
+                format!("cat: {}: {}", target, i18n::t("fs.is_a_directory")),
+                LineStyle::Error,
+            )],
             clear_screen: false,
             typewriter: true,
             start_game: None,
+            full_screen: None,
+            prompt: None,
+        };
+    }
+
+    if fs.is_binary(&resolved) {
+        return if image::is_image(&resolved) {
+            image::render_file(fs, &resolved, target, args)
+        } else {
+            error_output(format!("cat: {}: {}", target, i18n::t("fs.binary_file")))
         };
     }
 
     match fs.cat(&resolved) {
         Some(content) => {
-            let lines = content
-                .lines()
-                .map(|line| (line.to_string(), LineStyle::Normal))
-                .collect();
+            let lines = if !raw && resolved.ends_with(".md") {
+                markdown::render(&content)
+            } else {
+                content
+                    .lines()
+                    .map(|line| (line.to_string(), LineStyle::Normal))
+                    .collect()
+            };
             CommandOutput {
                 lines,
                 clear_screen: false,
                 typewriter: true,
                 start_game: None,
+                full_screen: None,
+                prompt: None,
             }
         }
         None => CommandOutput {
-            lines: vec![(format!("cat: {}: No such file", target), LineStyle::Error)],
+            lines: vec![(
+                format!("cat: {}: {}", target, i18n::t("fs.no_such_file")),
+                LineStyle::Error,
+            )],
             clear_screen: false,
             typewriter: true,
             start_game: None,
+            full_screen: None,
+            prompt: None,
         },
     }
 }
 
+pub fn grep(fs: &VirtualFs, cwd: &str, args: &[String]) -> CommandOutput {
+    let mut ignore_case = false;
+    let mut line_numbers = false;
+    let mut operands = Vec::new();
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.strip_prefix('-') {
+            // Everything after `--` is an operand, even a pattern like "-x"
+            Some("-") => {
+                operands.extend(args.by_ref());
+                break;
+            }
+            Some(flags) if !flags.is_empty() => {
+                for flag in flags.chars() {
+                    match flag {
+                        'i' => ignore_case = true,
+                        'n' => line_numbers = true,
+                        other => {
+                            return error_output(format!(
+                                "grep: {}",
+                                i18n::tr("fs.invalid_option", &[("option", &other.to_string())])
+                            ))
+                        }
+                    }
+                }
+            }
+            _ => operands.push(arg),
+        }
+    }
+
+    let Some((pattern, files)) = operands.split_first() else {
+        return error_output(format!(
+            "grep: {}",
+            i18n::tr(
+                "shell.usage",
+                &[("usage", "grep [-i] [-n] [--] <pattern> <file>...")]
+            )
+        ));
+    };
+    if files.is_empty() {
+        return error_output(format!("grep: {}", i18n::t("fs.missing_file_operand")));
+    }
+
+    let needle = if ignore_case {
+        pattern.to_lowercase()
+    } else {
+        pattern.to_string()
+    };
+
+    let mut lines = Vec::new();
+    for file in files {
+        let resolved = fs.resolve_path(cwd, file);
+        if fs.is_dir(&resolved) {
+            lines.push((
+                format!("grep: {}: {}", file, i18n::t("fs.is_a_directory")),
+                LineStyle::Error,
+            ));
+            continue;
+        }
+        if fs.is_binary(&resolved) {
+            continue;
+        }
+        let Some(content) = fs.cat(&resolved) else {
+            lines.push((
+                format!("grep: {}: {}", file, i18n::t("fs.no_such_file")),
+                LineStyle::Error,
+            ));
+            continue;
+        };
+
+        for (i, line) in content.lines().enumerate() {
+            let haystack = if ignore_case {
+                line.to_lowercase()
+            } else {
+                line.to_string()
+            };
+            if !haystack.contains(&needle) {
+                continue;
+            }
+
+            let mut prefix = String::new();
+            if files.len() > 1 {
+                prefix.push_str(&format!("{}:", file));
+            }
+            if line_numbers {
+                prefix.push_str(&format!("{}:", i + 1));
+            }
+            lines.push((format!("{}{}", prefix, line), LineStyle::Normal));
+        }
+    }
+
+    CommandOutput {
+        lines,
+        clear_screen: false,
+        typewriter: true,
+        start_game: None,
+        full_screen: None,
+        prompt: None,
+    }
+}
+
 pub fn tree(fs: &VirtualFs, cwd: &str, args: &[String]) -> CommandOutput {
-    let target = match args.first() {
+    let (show_hidden, paths) = split_all_flag(args);
+    let target = match paths.first() {
         Some(path) => fs.resolve_path(cwd, path),
         None => cwd.to_string(),
     };
 
-    match fs.tree(&target, "", true) {
+    match fs.tree(&target, "", true, show_hidden) {
         Some(lines) => CommandOutput {
             lines: lines
                 .into_iter()
@@ -147,15 +404,199 @@ pub fn tree(fs: &VirtualFs, cwd: &str, args: &[String]) -> CommandOutput {
             clear_screen: false,
             typewriter: true,
             start_game: None,
+            full_screen: None,
+            prompt: None,
         },
         None => CommandOutput {
             lines: vec![(
-                format!("tree: '{}': No such directory", target),
+                format!("tree: '{}': {}", target, i18n::t("fs.no_such_directory")),
                 LineStyle::Error,
             )],
             clear_screen: false,
             typewriter: true,
             start_game: None,
+            full_screen: None,
+            prompt: None,
         },
     }
 }
+
+pub fn touch(fs: &mut VirtualFs, cwd: &str, args: &[String]) -> CommandOutput {
+    if args.is_empty() {
+        return error_output(format!("touch: {}", i18n::t("fs.missing_file_operand")));
+    }
+
+    let mut lines = Vec::new();
+    for arg in args {
+        let resolved = fs.resolve_path(cwd, arg);
+        if fs.nodes.contains_key(&resolved) {
+            continue;
+        }
+        if let Err(err) = fs.write_file(&resolved, "") {
+            lines.push((
+                format!(
+                    "touch: {}",
+                    i18n::tr(
+                        "fs.cannot_touch",
+                        &[("path", arg), ("reason", &err.message())]
+                    )
+                ),
+                LineStyle::Error,
+            ));
+        }
+    }
+
+    CommandOutput {
+        lines,
+        clear_screen: false,
+        typewriter: true,
+        start_game: None,
+        full_screen: None,
+        prompt: None,
+    }
+}
+
+pub fn mkdir(fs: &mut VirtualFs, cwd: &str, args: &[String]) -> CommandOutput {
+    if args.is_empty() {
+        return error_output(format!("mkdir: {}", i18n::t("fs.missing_operand")));
+    }
+
+    let mut lines = Vec::new();
+    for arg in args {
+        let resolved = fs.resolve_path(cwd, arg);
+        if let Err(err) = fs.create_dir(&resolved) {
+            lines.push((
+                format!(
+                    "mkdir: {}",
+                    i18n::tr(
+                        "fs.cannot_mkdir",
+                        &[("path", arg), ("reason", &err.message())]
+                    )
+                ),
+                LineStyle::Error,
+            ));
+        }
+    }
+
+    CommandOutput {
+        lines,
+        clear_screen: false,
+        typewriter: true,
+        start_game: None,
+        full_screen: None,
+        prompt: None,
+    }
+}
+
+pub fn rm(fs: &mut VirtualFs, cwd: &str, args: &[String]) -> CommandOutput {
+    let mut recursive = false;
+    let mut force = false;
+    let mut paths = Vec::new();
+    for arg in args {
+        match arg.strip_prefix('-') {
+            Some(flags) if !flags.is_empty() => {
+                for flag in flags.chars() {
+                    match flag {
+                        'r' | 'R' => recursive = true,
+                        'f' => force = true,
+                        other => {
+                            return error_output(format!(
+                                "rm: {}",
+                                i18n::tr("fs.invalid_option", &[("option", &other.to_string())])
+                            ))
+                        }
+                    }
+                }
+            }
+            _ => paths.push(arg),
+        }
+    }
+    if paths.is_empty() {
+        return error_output(format!("rm: {}", i18n::t("fs.missing_operand")));
+    }
+
+    let mut lines = Vec::new();
+    for arg in paths {
+        let resolved = fs.resolve_path(cwd, arg);
+        let result = if recursive {
+            fs.remove_all(&resolved)
+        } else if fs.is_dir(&resolved) {
+            Err(FsError::IsADirectory)
+        } else {
+            fs.remove(&resolved)
+        };
+        match result {
+            // -f keeps quiet about paths that aren't there
+            Err(FsError::NotFound) if force => {}
+            Err(err) => lines.push((
+                format!(
+                    "rm: {}",
+                    i18n::tr(
+                        "fs.cannot_remove",
+                        &[("path", arg), ("reason", &err.message())]
+                    )
+                ),
+                LineStyle::Error,
+            )),
+            Ok(()) => {}
+        }
+    }
+
+    CommandOutput {
+        lines,
+        clear_screen: false,
+        typewriter: true,
+        start_game: None,
+        full_screen: None,
+        prompt: None,
+    }
+}
+
+pub fn reset_fs(fs: &mut VirtualFs, shell: &mut Shell) -> CommandOutput {
+    let changes = fs.overlay.entries.len();
+    *fs = VirtualFs {
+        session: fs.session,
+        ..builder::build_default_fs()
+    };
+
+    if !fs.is_dir(&shell.cwd) {
+        shell.cwd = HOME.to_string();
+    }
+    shell.dir_stack.retain(|dir| fs.is_dir(dir));
+
+    CommandOutput {
+        lines: vec![(
+            format!(
+                "  {}",
+                i18n::tr("fs.reset", &[("count", &changes.to_string())])
+            ),
+            LineStyle::Accent,
+        )],
+        clear_screen: false,
+        typewriter: false,
+        start_game: None,
+        full_screen: None,
+        prompt: None,
+    }
+}
+
+/// Pull `-a`/`--all` (also inside flag clusters like `-la`) out of the
+/// arguments, returning whether it was set and the remaining paths.
+fn split_all_flag(args: &[String]) -> (bool, Vec<&String>) {
+    let show_hidden = args
+        .iter()
+        .any(|a| a == "--all" || (a.starts_with('-') && !a.starts_with("--") && a.contains('a')));
+    let paths = args.iter().filter(|a| !a.starts_with('-')).collect();
+    (show_hidden, paths)
+}
+
+fn error_output(message: String) -> CommandOutput {
+    CommandOutput {
+        lines: vec![(message, LineStyle::Error)],
+        clear_screen: false,
+        typewriter: true,
+        start_game: None,
+        full_screen: None,
+        prompt: None,
+    }
+}
@@ -2,27 +2,112 @@ pub mod tictactoe;
 pub mod typing_test;
 
 use crate::commands::{CommandOutput, LineStyle};
+use crate::i18n;
 use tictactoe::TicTacToe;
 use typing_test::TypingTest;
 
-#[derive(Clone)]
-pub enum ActiveGame {
-    TicTacToe(TicTacToe),
-    TypingTest(TypingTest),
+const STORAGE_KEY: &str = "portfolio.game";
+
+/// A game played at the prompt. While one is running, each line the
+/// visitor enters goes to it instead of the shell, until it finishes or
+/// they type 'quit'.
+pub trait Game: Send + Sync {
+    /// The command that starts it
+    fn name(&self) -> &'static str;
+
+    /// Other commands that start it
+    fn aliases(&self) -> &'static [&'static str] {
+        &[]
+    }
+
+    /// English one-liner for `games` and `help`; translations live in the
+    /// catalogs under cmd.<name>
+    fn description(&self) -> &'static str;
+
+    /// The title, instructions and current position. Shown when the game
+    /// starts, and again when a saved game is resumed.
+    fn start_output(&self) -> CommandOutput;
+
+    fn handle_input(&mut self, input: &str) -> CommandOutput;
+
+    /// A key other than Enter pressed at the prompt. Returning output
+    /// consumes the key; `None` leaves it to the terminal, e.g. for history.
+    fn handle_key(&mut self, _key: &str) -> Option<CommandOutput> {
+        None
+    }
+
+    fn is_finished(&self) -> bool;
+
+    /// The game's progress as JSON, so it survives a reload.
+    fn save(&self) -> String;
+
+    /// Pick up from what `save` returned. False if it can't be read.
+    fn restore(&mut self, saved: &str) -> bool;
 }
 
-impl ActiveGame {
-    pub fn handle_input(&mut self, input: &str) -> CommandOutput {
-        match self {
-            ActiveGame::TicTacToe(game) => game.handle_input(input),
-            ActiveGame::TypingTest(game) => game.handle_input(input),
-        }
+/// Every game, in the order `games` lists them. Adding one here is all it
+/// takes to make it playable.
+const GAMES: &[fn() -> Box<dyn Game>] = &[
+    || Box::new(TicTacToe::new()),
+    || Box::new(TypingTest::new()),
+];
+
+pub fn all() -> Vec<Box<dyn Game>> {
+    GAMES.iter().map(|new| new()).collect()
+}
+
+/// A new game for the command that starts it, if any does.
+pub fn find(command: &str) -> Option<Box<dyn Game>> {
+    all()
+        .into_iter()
+        .find(|game| game.name() == command || game.aliases().contains(&command))
+}
+
+pub fn start(game: Box<dyn Game>) -> CommandOutput {
+    game.start_output().with_game(game)
+}
+
+pub fn games() -> CommandOutput {
+    let mut lines = vec![
+        (String::new(), LineStyle::Normal),
+        (format!("  {}", i18n::t("games.title")), LineStyle::Accent),
+        (String::new(), LineStyle::Normal),
+    ];
+    for game in all() {
+        let description = i18n::t_or(&format!("cmd.{}", game.name()), game.description());
+        lines.push((
+            format!("  {:<12}{}", game.name(), description),
+            LineStyle::Normal,
+        ));
     }
+    lines.push((String::new(), LineStyle::Normal));
+    lines.push((format!("  {}", i18n::t("games.hint")), LineStyle::Muted));
+    lines.push((String::new(), LineStyle::Normal));
+    CommandOutput::new(lines, false, true)
+}
+
+fn local_storage() -> Option<web_sys::Storage> {
+    web_sys::window()?.local_storage().ok()?
+}
 
-    pub fn is_finished(&self) -> bool {
-        match self {
-            ActiveGame::TicTacToe(game) => game.game_over,
-            ActiveGame::TypingTest(game) => game.finished,
+/// The game left running when the page was last closed, if it can still
+/// be picked up.
+pub fn load() -> Option<Box<dyn Game>> {
+    let json = local_storage()?.get_item(STORAGE_KEY).ok()??;
+    let saved: serde_json::Value = serde_json::from_str(&json).ok()?;
+    let mut game = find(saved["name"].as_str()?)?;
+    (game.restore(saved["state"].as_str()?) && !game.is_finished()).then_some(game)
+}
+
+pub fn save(game: Option<&dyn Game>) {
+    let Some(storage) = local_storage() else {
+        return;
+    };
+    let _ = match game {
+        Some(game) => {
+            let saved = serde_json::json!({ "name": game.name(), "state": game.save() });
+            storage.set_item(STORAGE_KEY, &saved.to_string())
         }
-    }
+        None => storage.remove_item(STORAGE_KEY),
+    };
 }
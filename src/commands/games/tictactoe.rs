@@ -1,113 +1,22 @@
-use crate::commands::{games, CommandOutput, LineStyle};
+use super::Game;
+use crate::commands::{CommandOutput, LineStyle};
+use crate::i18n;
+use serde::{Deserialize, Serialize};
 
-#[derive(Clone)]
+#[derive(Clone, Serialize, Deserialize)]
 pub struct TicTacToe {
     board: [Option<char>; 9],
-    player_turn: bool,
-    pub game_over: bool,
+    game_over: bool,
 }
 
 impl TicTacToe {
     pub fn new() -> Self {
         Self {
             board: [None; 9],
-            player_turn: true,
             game_over: false,
         }
     }
 
-    pub fn start_output() -> CommandOutput {
-        let mut lines = vec![
-            (String::new(), LineStyle::Normal),
-            ("  === TIC-TAC-TOE ===".to_string(), LineStyle::Accent),
-            (
-                "  You are X. Enter a number 1-9 to place your mark.".to_string(),
-                LineStyle::Normal,
-            ),
-            (
-                "  Type 'quit' to exit the game.".to_string(),
-                LineStyle::Muted,
-            ),
-            (String::new(), LineStyle::Normal),
-        ];
-
-        let board_lines = Self::render_empty_board();
-        lines.extend(board_lines);
-        lines.push((String::new(), LineStyle::Normal));
-        lines.push(("  Your move (1-9):".to_string(), LineStyle::Accent));
-
-        CommandOutput::new(lines, false, false)
-    }
-
-    pub fn handle_input(&mut self, input: &str) -> CommandOutput {
-        let input = input.trim().to_lowercase();
-
-        if self.game_over {
-            return Self::start_output();
-        }
-
-        // Parse move
-        let pos: usize = match input.parse::<usize>() {
-            Ok(n) if n >= 1 && n <= 9 => n - 1,
-            _ => {
-                return CommandOutput::new(
-                    vec![(
-                        "  Invalid move! Enter a number 1-9.".to_string(),
-                        LineStyle::Error,
-                    )],
-                    false,
-                    false,
-                );
-            }
-        };
-
-        // Check if position is taken
-        if self.board[pos].is_some() {
-            return CommandOutput::new(
-                vec![(
-                    "  That spot is taken! Try another.".to_string(),
-                    LineStyle::Error,
-                )],
-                false,
-                false,
-            );
-        }
-
-        // Player move
-        self.board[pos] = Some('X');
-
-        if let Some(winner) = self.check_winner() {
-            self.game_over = true;
-            return self.game_over_output(winner);
-        }
-
-        if self.is_full() {
-            self.game_over = true;
-            return self.game_over_output(' ');
-        }
-
-        // Computer move
-        self.computer_move();
-
-        if let Some(winner) = self.check_winner() {
-            self.game_over = true;
-            return self.game_over_output(winner);
-        }
-
-        if self.is_full() {
-            self.game_over = true;
-            return self.game_over_output(' ');
-        }
-
-        // Show board and prompt next move
-        let mut lines = vec![(String::new(), LineStyle::Normal)];
-        lines.extend(self.render_board());
-        lines.push((String::new(), LineStyle::Normal));
-        lines.push(("  Your move (1-9):".to_string(), LineStyle::Accent));
-
-        CommandOutput::new(lines, false, false)
-    }
-
     fn computer_move(&mut self) {
         // Simple AI: try to win, then block, then take center, then random
         if let Some(pos) = self.find_winning_move('O') {
@@ -118,11 +27,8 @@ impl TicTacToe {
             self.board[4] = Some('O');
         } else {
             // Take first available
-            for i in 0..9 {
-                if self.board[i].is_none() {
-                    self.board[i] = Some('O');
-                    break;
-                }
+            if let Some(cell) = self.board.iter_mut().find(|c| c.is_none()) {
+                *cell = Some('O');
             }
         }
     }
@@ -186,23 +92,17 @@ impl TicTacToe {
 
         match winner {
             'X' => {
-                lines.push(("  You win! Congratulations!".to_string(), LineStyle::Accent));
+                lines.push((format!("  {}", i18n::t("ttt.win")), LineStyle::Accent));
             }
             'O' => {
-                lines.push((
-                    "  Computer wins! Better luck next time.".to_string(),
-                    LineStyle::Error,
-                ));
+                lines.push((format!("  {}", i18n::t("ttt.lose")), LineStyle::Error));
             }
             _ => {
-                lines.push(("  It's a draw!".to_string(), LineStyle::Muted));
+                lines.push((format!("  {}", i18n::t("ttt.draw")), LineStyle::Muted));
             }
         }
 
-        lines.push((
-            "  Type 'ttt' to play again, or 'quit' to exit.".to_string(),
-            LineStyle::Muted,
-        ));
+        lines.push((format!("  {}", i18n::t("ttt.again")), LineStyle::Muted));
 
         CommandOutput::new(lines, false, false)
     }
@@ -233,14 +133,115 @@ impl TicTacToe {
             ),
         ]
     }
+}
 
-    fn render_empty_board() -> Vec<(String, LineStyle)> {
-        vec![
-            ("   1 | 2 | 3".to_string(), LineStyle::Normal),
-            ("   ---------".to_string(), LineStyle::Muted),
-            ("   4 | 5 | 6".to_string(), LineStyle::Normal),
-            ("   ---------".to_string(), LineStyle::Muted),
-            ("   7 | 8 | 9".to_string(), LineStyle::Normal),
-        ]
+impl Game for TicTacToe {
+    fn name(&self) -> &'static str {
+        "ttt"
+    }
+
+    fn aliases(&self) -> &'static [&'static str] {
+        &["tictactoe"]
+    }
+
+    fn description(&self) -> &'static str {
+        "Play Tic-Tac-Toe"
+    }
+
+    fn start_output(&self) -> CommandOutput {
+        let mut lines = vec![
+            (String::new(), LineStyle::Normal),
+            (format!("  {}", i18n::t("ttt.title")), LineStyle::Accent),
+            (format!("  {}", i18n::t("ttt.intro")), LineStyle::Normal),
+            (format!("  {}", i18n::t("game.quit_hint")), LineStyle::Muted),
+            (String::new(), LineStyle::Normal),
+        ];
+
+        lines.extend(self.render_board());
+        lines.push((String::new(), LineStyle::Normal));
+        lines.push((format!("  {}", i18n::t("ttt.your_move")), LineStyle::Accent));
+
+        CommandOutput::new(lines, false, false)
+    }
+
+    fn handle_input(&mut self, input: &str) -> CommandOutput {
+        let input = input.trim().to_lowercase();
+
+        if self.game_over {
+            return Self::new().start_output();
+        }
+
+        // Parse move
+        let pos: usize = match input.parse::<usize>() {
+            Ok(n) if (1..=9).contains(&n) => n - 1,
+            _ => {
+                return CommandOutput::new(
+                    vec![(format!("  {}", i18n::t("ttt.invalid")), LineStyle::Error)],
+                    false,
+                    false,
+                );
+            }
+        };
+
+        // Check if position is taken
+        if self.board[pos].is_some() {
+            return CommandOutput::new(
+                vec![(format!("  {}", i18n::t("ttt.taken")), LineStyle::Error)],
+                false,
+                false,
+            );
+        }
+
+        // Player move
+        self.board[pos] = Some('X');
+
+        if let Some(winner) = self.check_winner() {
+            self.game_over = true;
+            return self.game_over_output(winner);
+        }
+
+        if self.is_full() {
+            self.game_over = true;
+            return self.game_over_output(' ');
+        }
+
+        // Computer move
+        self.computer_move();
+
+        if let Some(winner) = self.check_winner() {
+            self.game_over = true;
+            return self.game_over_output(winner);
+        }
+
+        if self.is_full() {
+            self.game_over = true;
+            return self.game_over_output(' ');
+        }
+
+        // Show board and prompt next move
+        let mut lines = vec![(String::new(), LineStyle::Normal)];
+        lines.extend(self.render_board());
+        lines.push((String::new(), LineStyle::Normal));
+        lines.push((format!("  {}", i18n::t("ttt.your_move")), LineStyle::Accent));
+
+        CommandOutput::new(lines, false, false)
+    }
+
+    fn is_finished(&self) -> bool {
+        self.game_over
+    }
+
+    fn save(&self) -> String {
+        serde_json::to_string(self).unwrap_or_default()
+    }
+
+    fn restore(&mut self, saved: &str) -> bool {
+        match serde_json::from_str(saved) {
+            Ok(game) => {
+                *self = game;
+                true
+            }
+            Err(_) => false,
+        }
     }
 }
@@ -1,4 +1,7 @@
-use crate::commands::{games, CommandOutput, LineStyle};
+use super::Game;
+use crate::commands::{CommandOutput, LineStyle};
+use crate::i18n;
+use serde::{Deserialize, Serialize};
 
 const SENTENCES: &[&str] = &[
     "the quick brown fox jumps over the lazy dog",
@@ -11,11 +14,11 @@ const SENTENCES: &[&str] = &[
     "open source software powers most of the internet today",
 ];
 
-#[derive(Clone)]
+#[derive(Clone, Serialize, Deserialize)]
 pub struct TypingTest {
-    pub target: String,
-    pub start_time: Option<f64>,
-    pub finished: bool,
+    target: String,
+    start_time: Option<f64>,
+    finished: bool,
 }
 
 impl TypingTest {
@@ -29,17 +32,27 @@ impl TypingTest {
             finished: false,
         }
     }
+}
+
+impl Game for TypingTest {
+    fn name(&self) -> &'static str {
+        "typing"
+    }
 
-    pub fn start_output(&self) -> CommandOutput {
+    fn description(&self) -> &'static str {
+        "Typing speed test"
+    }
+
+    fn start_output(&self) -> CommandOutput {
         CommandOutput::new(
             vec![
                 (String::new(), LineStyle::Normal),
-                ("  === TYPING SPEED TEST ===".to_string(), LineStyle::Accent),
+                (format!("  {}", i18n::t("typing.title")), LineStyle::Accent),
+                (format!("  {}", i18n::t("typing.intro")), LineStyle::Normal),
                 (
-                    "  Type the following text as fast as you can:".to_string(),
-                    LineStyle::Normal,
+                    format!("  {}", i18n::t("typing.quit_hint")),
+                    LineStyle::Muted,
                 ),
-                ("  Type 'quit' to exit.".to_string(), LineStyle::Muted),
                 (String::new(), LineStyle::Normal),
                 (format!("  > {}", self.target), LineStyle::Accent),
                 (String::new(), LineStyle::Normal),
@@ -49,7 +62,7 @@ impl TypingTest {
         )
     }
 
-    pub fn handle_input(&mut self, input: &str) -> CommandOutput {
+    fn handle_input(&mut self, input: &str) -> CommandOutput {
         let input = input.trim();
 
         // Start timer on first input
@@ -89,35 +102,74 @@ impl TypingTest {
 
         self.finished = true;
 
-        let rating = match wpm {
-            0..=29 => "Keep practicing!",
-            30..=49 => "Not bad!",
-            50..=69 => "Good speed!",
-            70..=89 => "Impressive!",
-            90..=119 => "Blazing fast!",
-            _ => "Are you a robot?!",
-        };
+        let rating = i18n::t(match wpm {
+            0..=29 => "typing.rating_0",
+            30..=49 => "typing.rating_1",
+            50..=69 => "typing.rating_2",
+            70..=89 => "typing.rating_3",
+            90..=119 => "typing.rating_4",
+            _ => "typing.rating_5",
+        });
+
+        // Labels differ in length between languages, so line the values up
+        // after the longest one
+        let labels = [
+            "typing.speed",
+            "typing.accuracy",
+            "typing.time",
+            "typing.rating",
+        ]
+        .map(|key| format!("{}:", i18n::t(key)));
+        let width = labels.iter().map(|l| l.chars().count()).max().unwrap_or(0) + 1;
+        let [speed_label, accuracy_label, time_label, rating_label] = labels;
 
         CommandOutput::new(
             vec![
                 (String::new(), LineStyle::Normal),
-                ("  === RESULTS ===".to_string(), LineStyle::Accent),
+                (
+                    format!("  {}", i18n::t("typing.results")),
+                    LineStyle::Accent,
+                ),
                 (String::new(), LineStyle::Normal),
-                (format!("  Speed:    {} WPM", wpm), LineStyle::Normal),
-                (format!("  Accuracy: {}%", accuracy), LineStyle::Normal),
                 (
-                    format!("  Time:     {:.1}s", elapsed_secs),
+                    format!("  {:<width$}{} WPM", speed_label, wpm),
                     LineStyle::Normal,
                 ),
-                (format!("  Rating:   {}", rating), LineStyle::Accent),
-                (String::new(), LineStyle::Normal),
                 (
-                    "  Type 'typing' to try again, or 'quit' to exit.".to_string(),
-                    LineStyle::Muted,
+                    format!("  {:<width$}{}%", accuracy_label, accuracy),
+                    LineStyle::Normal,
                 ),
+                (
+                    format!("  {:<width$}{:.1}s", time_label, elapsed_secs),
+                    LineStyle::Normal,
+                ),
+                (
+                    format!("  {:<width$}{}", rating_label, rating),
+                    LineStyle::Accent,
+                ),
+                (String::new(), LineStyle::Normal),
+                (format!("  {}", i18n::t("typing.again")), LineStyle::Muted),
             ],
             false,
             false,
         )
     }
+
+    fn is_finished(&self) -> bool {
+        self.finished
+    }
+
+    fn save(&self) -> String {
+        serde_json::to_string(self).unwrap_or_default()
+    }
+
+    fn restore(&mut self, saved: &str) -> bool {
+        match serde_json::from_str(saved) {
+            Ok(test) => {
+                *self = test;
+                true
+            }
+            Err(_) => false,
+        }
+    }
 }
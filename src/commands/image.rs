@@ -0,0 +1,257 @@
+use base64::Engine;
+
+use crate::commands::{download, CommandOutput, LineStyle};
+use crate::filesystem::model::VirtualFs;
+use crate::i18n;
+use crate::state::viewport;
+
+const ASCII_RAMP: &[char] = &[' ', '.', ':', '-', '=', '+', '*', '#', '%', '@'];
+const MAX_WIDTH: usize = 80;
+
+// Terminal cells are roughly 8.4px wide and 22.4px tall
+const CELL_ASPECT: f64 = 8.4 / 22.4;
+
+// 4x4 ordered-dither thresholds, scaled to 0..1 when used
+const BAYER: [[u8; 4]; 4] = [[0, 8, 2, 10], [12, 4, 14, 6], [3, 11, 1, 9], [15, 7, 13, 5]];
+
+#[derive(Clone, Copy, PartialEq)]
+enum Mode {
+    Blocks,
+    Ascii,
+    Inline,
+}
+
+/// Grayscale pixels in 0.0 (dark) ..= 1.0 (bright).
+struct Bitmap {
+    width: usize,
+    height: usize,
+    luma: Vec<f32>,
+}
+
+impl Bitmap {
+    fn decode_png(data: &[u8]) -> Result<Self, String> {
+        let mut decoder = png::Decoder::new(data);
+        decoder.set_transformations(png::Transformations::EXPAND | png::Transformations::STRIP_16);
+        let mut reader = decoder.read_info().map_err(|e| e.to_string())?;
+        let mut buf = vec![0; reader.output_buffer_size()];
+        let info = reader.next_frame(&mut buf).map_err(|e| e.to_string())?;
+
+        let channels = info.color_type.samples();
+        let mut luma: Vec<f32> = buf[..info.buffer_size()]
+            .chunks_exact(channels)
+            .map(|px| {
+                let (value, alpha) = match px {
+                    [g] => (*g as f32, 255.0),
+                    [g, a] => (*g as f32, *a as f32),
+                    [r, g, b] => (luminance(*r, *g, *b), 255.0),
+                    [r, g, b, a, ..] => (luminance(*r, *g, *b), *a as f32),
+                    _ => (0.0, 0.0),
+                };
+                // Transparent pixels fade into the dark terminal background
+                value / 255.0 * alpha / 255.0
+            })
+            .collect();
+
+        // Stretch contrast so dim backgrounds don't dither into noise
+        let (min, max) = luma
+            .iter()
+            .fold((1.0f32, 0.0f32), |(lo, hi), &v| (lo.min(v), hi.max(v)));
+        if max - min > f32::EPSILON {
+            for value in &mut luma {
+                *value = (*value - min) / (max - min);
+            }
+        }
+
+        Ok(Self {
+            width: info.width as usize,
+            height: info.height as usize,
+            luma,
+        })
+    }
+
+    /// Average brightness of the source area covered by one output pixel.
+    fn sample(&self, x: usize, y: usize, out_w: usize, out_h: usize) -> f32 {
+        let x0 = x * self.width / out_w;
+        let x1 = ((x + 1) * self.width / out_w).max(x0 + 1);
+        let y0 = y * self.height / out_h;
+        let y1 = ((y + 1) * self.height / out_h).max(y0 + 1);
+
+        let mut total = 0.0;
+        for sy in y0..y1 {
+            for sx in x0..x1 {
+                total += self.luma[sy * self.width + sx];
+            }
+        }
+        total / ((x1 - x0) * (y1 - y0)) as f32
+    }
+
+    /// Output rows needed to keep the aspect ratio for `cols` columns,
+    /// given how many pixels each cell stacks vertically.
+    fn rows_for(&self, cols: usize, pixels_per_cell: usize) -> usize {
+        let rows = self.height as f64 / self.width as f64 * cols as f64 * CELL_ASPECT;
+        ((rows * pixels_per_cell as f64).round() as usize).max(1)
+    }
+
+    fn render_ascii(&self, cols: usize) -> Vec<String> {
+        let rows = self.rows_for(cols, 1);
+        (0..rows)
+            .map(|y| {
+                (0..cols)
+                    .map(|x| {
+                        let level = self.sample(x, y, cols, rows);
+                        let idx = (level * (ASCII_RAMP.len() - 1) as f32).round() as usize;
+                        ASCII_RAMP[idx.min(ASCII_RAMP.len() - 1)]
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Two dithered pixels per cell using the upper/lower half-block glyphs.
+    fn render_blocks(&self, cols: usize) -> Vec<String> {
+        let pixel_rows = self.rows_for(cols, 2);
+        let lit = |x: usize, y: usize| {
+            if y >= pixel_rows {
+                return false;
+            }
+            let threshold = (BAYER[y % 4][x % 4] as f32 + 0.5) / 16.0;
+            // Squaring darkens the shadows so dim areas stay empty instead of speckled
+            self.sample(x, y, cols, pixel_rows).powi(2) > threshold
+        };
+
+        (0..pixel_rows.div_ceil(2))
+            .map(|row| {
+                (0..cols)
+                    .map(|x| match (lit(x, row * 2), lit(x, row * 2 + 1)) {
+                        (true, true) => '█',
+                        (true, false) => '▀',
+                        (false, true) => '▄',
+                        (false, false) => ' ',
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+}
+
+fn luminance(r: u8, g: u8, b: u8) -> f32 {
+    0.299 * r as f32 + 0.587 * g as f32 + 0.114 * b as f32
+}
+
+/// Images that can be drawn in the terminal. Only PNG is decoded.
+pub fn is_image(path: &str) -> bool {
+    download::mime_type(path) == "image/png"
+}
+
+/// The file named in `args`, skipping flags and the value after `--width`.
+pub fn file_operand(args: &[String]) -> Option<&String> {
+    args.iter()
+        .enumerate()
+        .find(|(i, a)| !a.starts_with("--") && (*i == 0 || args[i - 1] != "--width"))
+        .map(|(_, a)| a)
+}
+
+/// Render an image file as terminal art. Shared by `view` and `cat`.
+pub fn render_file(fs: &VirtualFs, path: &str, label: &str, args: &[String]) -> CommandOutput {
+    let mode = if args.iter().any(|a| a == "--inline") {
+        Mode::Inline
+    } else if args.iter().any(|a| a == "--ascii") {
+        Mode::Ascii
+    } else {
+        Mode::Blocks
+    };
+
+    let Some(data) = fs.read_bytes(path) else {
+        return error(format!("{}: {}", label, i18n::t("fs.no_such_file")));
+    };
+
+    if mode == Mode::Inline {
+        let src = format!(
+            "data:{};base64,{}",
+            download::mime_type(path),
+            base64::engine::general_purpose::STANDARD.encode(&data)
+        );
+        return CommandOutput::new(
+            vec![(label.to_string(), LineStyle::Image(src))],
+            false,
+            false,
+        );
+    }
+
+    let bitmap = match Bitmap::decode_png(&data) {
+        Ok(bitmap) => bitmap,
+        Err(err) => {
+            return error(format!(
+                "{}: {}",
+                label,
+                i18n::tr("image.cannot_decode", &[("reason", &err)])
+            ))
+        }
+    };
+
+    let requested = args
+        .iter()
+        .position(|a| a == "--width")
+        .and_then(|i| args.get(i + 1))
+        .and_then(|w| w.parse::<usize>().ok());
+    let cols = requested
+        .unwrap_or_else(|| viewport::columns().saturating_sub(4).min(MAX_WIDTH))
+        .clamp(8, bitmap.width.max(8));
+
+    let art = match mode {
+        Mode::Ascii => bitmap.render_ascii(cols),
+        _ => bitmap.render_blocks(cols),
+    };
+
+    let mut lines: Vec<(String, LineStyle)> = art
+        .into_iter()
+        .map(|line| (format!("  {}", line), LineStyle::Accent))
+        .collect();
+    lines.push((
+        format!(
+            "  {}",
+            i18n::tr(
+                "image.caption",
+                &[
+                    ("file", label),
+                    ("width", &bitmap.width.to_string()),
+                    ("height", &bitmap.height.to_string()),
+                ],
+            )
+        ),
+        LineStyle::Muted,
+    ));
+
+    CommandOutput::new(lines, false, false)
+}
+
+pub fn view(fs: &VirtualFs, cwd: &str, args: &[String]) -> CommandOutput {
+    let Some(target) = file_operand(args) else {
+        return error(format!("view: {}", i18n::t("fs.missing_file_operand")));
+    };
+
+    let resolved = fs.resolve_path(cwd, target);
+    if fs.is_dir(&resolved) {
+        return error(format!(
+            "view: {}: {}",
+            target,
+            i18n::t("fs.is_a_directory")
+        ));
+    }
+    if !fs.is_file(&resolved) {
+        return error(format!("view: {}: {}", target, i18n::t("fs.no_such_file")));
+    }
+    if !is_image(&resolved) {
+        return error(format!(
+            "view: {}: {}",
+            target,
+            i18n::t("image.not_an_image")
+        ));
+    }
+
+    render_file(fs, &resolved, target, args)
+}
+
+fn error(message: String) -> CommandOutput {
+    CommandOutput::new(vec![(message, LineStyle::Error)], false, false)
+}
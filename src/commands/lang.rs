@@ -0,0 +1,75 @@
+use crate::commands::{CommandOutput, LineStyle};
+use crate::filesystem::builder;
+use crate::filesystem::model::VirtualFs;
+use crate::i18n::{self, LOCALES};
+
+pub fn lang(fs: &mut VirtualFs, args: &[String]) -> CommandOutput {
+    let Some(tag) = args.first() else {
+        return current();
+    };
+    let Some(code) = i18n::negotiate(tag) else {
+        return CommandOutput::new(
+            vec![(
+                format!("lang: {}", i18n::tr("lang.unknown", &[("code", tag)])),
+                LineStyle::Error,
+            )],
+            false,
+            false,
+        );
+    };
+
+    i18n::set(code);
+    // Files generated from the content are rebuilt in the new language,
+    // keeping whatever the visitor changed on top
+    let overlay = std::mem::take(&mut fs.overlay);
+    *fs = VirtualFs {
+        session: fs.session,
+        ..builder::build_default_fs()
+    };
+    fs.apply_overlay(overlay);
+
+    CommandOutput::new(
+        vec![(
+            format!(
+                "  {}",
+                i18n::tr("lang.switched", &[("name", i18n::name(code))])
+            ),
+            LineStyle::Accent,
+        )],
+        false,
+        false,
+    )
+}
+
+fn current() -> CommandOutput {
+    let code = i18n::current();
+    let mut lines = vec![
+        (String::new(), LineStyle::Normal),
+        (
+            format!(
+                "  {}",
+                i18n::tr(
+                    "lang.current",
+                    &[("name", i18n::name(code)), ("code", code)]
+                )
+            ),
+            LineStyle::Accent,
+        ),
+        (String::new(), LineStyle::Normal),
+        (
+            format!("  {}", i18n::t("lang.available")),
+            LineStyle::Normal,
+        ),
+    ];
+    for (locale, name) in LOCALES {
+        let marker = if *locale == code { "*" } else { " " };
+        lines.push((
+            format!("  {} {:<4}{}", marker, locale, name),
+            LineStyle::Normal,
+        ));
+    }
+    lines.push((String::new(), LineStyle::Normal));
+    lines.push((format!("  {}", i18n::t("lang.hint")), LineStyle::Muted));
+    lines.push((String::new(), LineStyle::Normal));
+    CommandOutput::new(lines, false, true)
+}
@@ -0,0 +1,213 @@
+use crate::commands::{CommandOutput, LineStyle};
+use crate::i18n;
+use std::collections::HashSet;
+
+/// Bare domains are only linked for these endings, so file names like
+/// README.md or about.txt stay plain text.
+const TLDS: &[&str] = &["com", "org", "net", "io", "dev", "app", "me", "co", "ai"];
+
+/// A piece of an output line: plain text, or an anchor with its label.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Span {
+    Text(String),
+    Link { href: String, label: String },
+}
+
+/// Split a line into text and links, detecting URLs, bare domains and
+/// email addresses.
+pub fn spans(line: &str) -> Vec<Span> {
+    let mut spans = Vec::new();
+    let mut text = String::new();
+
+    for token in line.split_inclusive(char::is_whitespace) {
+        let word = token.trim_end();
+        match find_link(word) {
+            Some((start, end, href)) => {
+                text.push_str(&word[..start]);
+                if !text.is_empty() {
+                    spans.push(Span::Text(std::mem::take(&mut text)));
+                }
+                spans.push(Span::Link {
+                    href,
+                    label: word[start..end].to_string(),
+                });
+                text.push_str(&token[end..]);
+            }
+            None => text.push_str(token),
+        }
+    }
+
+    if !text.is_empty() {
+        spans.push(Span::Text(text));
+    }
+    spans
+}
+
+/// Every href in the output, in the order a reader meets them.
+pub fn collect(lines: &[(String, LineStyle)]) -> Vec<String> {
+    let mut links = Vec::new();
+    for (text, style) in lines {
+        match style {
+            LineStyle::Link(href) => links.push(href.clone()),
+            LineStyle::Image(_) => {}
+            _ => links.extend(spans(text).into_iter().filter_map(|span| match span {
+                Span::Link { href, .. } => Some(href),
+                Span::Text(_) => None,
+            })),
+        }
+    }
+    // Each link keeps the number of its first appearance
+    let mut seen = HashSet::new();
+    links.retain(|href| seen.insert(href.clone()));
+    links
+}
+
+/// Find the link inside one whitespace-free word: its byte range and href.
+fn find_link(word: &str) -> Option<(usize, usize, String)> {
+    // A full URL can start anywhere, e.g. inside a markdown [label](url)
+    if let Some(start) = word.find("https://").or_else(|| word.find("http://")) {
+        let url = trim_trailing(&word[start..]);
+        return url
+            .contains("://")
+            .then(|| (start, start + url.len(), url.to_string()));
+    }
+
+    let start = word.len() - word.trim_start_matches(['(', '[', '<', '"', '\'']).len();
+    let candidate = trim_trailing(&word[start..]);
+    let end = start + candidate.len();
+
+    if is_email(candidate) {
+        Some((start, end, format!("mailto:{}", candidate)))
+    } else if is_domain(candidate) {
+        Some((start, end, format!("https://{}", candidate)))
+    } else {
+        None
+    }
+}
+
+/// Drop sentence punctuation and unbalanced closing brackets from the end.
+fn trim_trailing(word: &str) -> &str {
+    let mut word = word;
+    loop {
+        let Some(last) = word.chars().last() else {
+            return word;
+        };
+        let unbalanced = |open: char| word.matches(open).count() < word.matches(last).count();
+        let strip = match last {
+            '.' | ',' | ';' | ':' | '!' | '?' | '\'' | '"' | '>' => true,
+            ')' => unbalanced('('),
+            ']' => unbalanced('['),
+            _ => false,
+        };
+        if !strip {
+            return word;
+        }
+        word = &word[..word.len() - last.len_utf8()];
+    }
+}
+
+pub fn is_email(word: &str) -> bool {
+    let Some((user, host)) = word.split_once('@') else {
+        return false;
+    };
+    !user.is_empty()
+        && user
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || "._%+-".contains(c))
+        && is_host(host, |tld| {
+            tld.len() >= 2 && tld.chars().all(|c| c.is_ascii_alphabetic())
+        })
+}
+
+fn is_domain(word: &str) -> bool {
+    let host = word.split('/').next().unwrap_or_default();
+    is_host(host, |tld| TLDS.contains(&tld))
+}
+
+fn is_host(host: &str, tld_ok: impl Fn(&str) -> bool) -> bool {
+    let labels: Vec<&str> = host.split('.').collect();
+    labels.len() >= 2
+        && labels.iter().all(|label| {
+            !label.is_empty() && label.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+        })
+        && labels.last().is_some_and(|tld| tld_ok(&tld.to_lowercase()))
+}
+
+pub fn open(args: &[String], links: &[String]) -> CommandOutput {
+    let Some(target) = args.first() else {
+        if links.is_empty() {
+            return error(format!("open: {}", i18n::t("open.no_links")));
+        }
+        let mut lines = vec![(String::new(), LineStyle::Normal)];
+        for (i, href) in links.iter().enumerate() {
+            lines.push((
+                format!("  {}. {}", i + 1, href),
+                LineStyle::Link(href.clone()),
+            ));
+        }
+        lines.push((String::new(), LineStyle::Normal));
+        lines.push((i18n::t("open.hint"), LineStyle::Muted));
+        return CommandOutput::new(lines, false, false);
+    };
+
+    let href = match target.parse::<usize>() {
+        Ok(n) => match n.checked_sub(1).and_then(|i| links.get(i)) {
+            Some(href) => href.clone(),
+            None => {
+                return error(format!(
+                    "open: {}",
+                    i18n::tr(
+                        "open.no_link",
+                        &[("n", &n.to_string()), ("count", &links.len().to_string())],
+                    )
+                ))
+            }
+        },
+        Err(_) => match find_link(target) {
+            Some((0, end, href)) if end == target.len() => href,
+            _ => {
+                return error(format!(
+                    "open: {}",
+                    i18n::tr("open.not_a_link", &[("target", target)])
+                ))
+            }
+        },
+    };
+
+    let Some(window) = web_sys::window() else {
+        return error(format!(
+            "open: {}",
+            i18n::tr("open.failed", &[("href", &href)])
+        ));
+    };
+    match window.open_with_url_and_target(&href, "_blank") {
+        Ok(Some(_)) => CommandOutput::new(
+            vec![(
+                format!("  {}", i18n::tr("open.opening", &[("href", &href)])),
+                LineStyle::Link(href),
+            )],
+            false,
+            false,
+        ),
+        // A popup blocker hands back no window rather than an error
+        Ok(None) => CommandOutput::new(
+            vec![
+                (
+                    format!("open: {}", i18n::t("open.blocked")),
+                    LineStyle::Error,
+                ),
+                (format!("  {}", href), LineStyle::Link(href)),
+            ],
+            false,
+            false,
+        ),
+        Err(_) => error(format!(
+            "open: {}",
+            i18n::tr("open.failed", &[("href", &href)])
+        )),
+    }
+}
+
+fn error(message: String) -> CommandOutput {
+    CommandOutput::new(vec![(message, LineStyle::Error)], false, false)
+}
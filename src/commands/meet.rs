@@ -0,0 +1,292 @@
+use crate::commands::prompt::{self, Field, PromptSession, Purpose};
+use crate::commands::{contact, download, CommandOutput, LineStyle};
+use crate::i18n;
+use crate::portfolio::calendar::{self, Invite};
+use crate::portfolio::portfolio;
+
+const DEFAULT_MINUTES: u32 = 30;
+
+/// Questions asked by `meet`, in order.
+const FIELDS: &[Field] = &[
+    Field {
+        label: "meet.field.date",
+        multiline: false,
+        validate: valid_date,
+    },
+    Field {
+        label: "meet.field.time",
+        multiline: false,
+        validate: valid_time,
+    },
+    Field {
+        label: "meet.field.length",
+        multiline: false,
+        validate: valid_length,
+    },
+    Field {
+        label: "meet.field.name",
+        multiline: false,
+        validate: prompt::required,
+    },
+    Field {
+        label: "meet.field.email",
+        multiline: false,
+        validate: contact::valid_email,
+    },
+    Field {
+        label: "meet.field.topic",
+        multiline: false,
+        validate: prompt::required,
+    },
+];
+
+const ACTIONS: &[(&str, &str)] = &[("d", "meet.action.download")];
+
+/// A meeting request from `meet`.
+const MEETING: Purpose = Purpose {
+    fields: FIELDS,
+    preview,
+    actions: ACTIONS,
+    finish,
+};
+
+pub fn meet() -> CommandOutput {
+    if contact::recipient().is_none() {
+        return CommandOutput::new(
+            vec![(
+                format!("meet: {}", i18n::t("meet.no_recipient")),
+                LineStyle::Error,
+            )],
+            false,
+            false,
+        );
+    }
+
+    let mut lines = vec![
+        (String::new(), LineStyle::Normal),
+        (
+            format!(
+                "  {}",
+                i18n::tr("meet.proposing", &[("name", &portfolio().profile.name)])
+            ),
+            LineStyle::Accent,
+        ),
+    ];
+    lines.extend(
+        i18n::t("meet.instructions")
+            .lines()
+            .map(|line| (format!("  {}", line), LineStyle::Muted)),
+    );
+    lines.push((String::new(), LineStyle::Normal));
+    CommandOutput::new(lines, false, false).with_prompt(PromptSession::new(MEETING))
+}
+
+fn valid_date(answer: &str) -> Result<(), String> {
+    let Some((year, month, day)) = parse_date(answer) else {
+        return Err(i18n::tr("meet.bad_date", &[("answer", answer)]));
+    };
+    let today = js_sys::Date::new_0();
+    let today = (
+        today.get_full_year(),
+        today.get_month() + 1,
+        today.get_date(),
+    );
+    if (year, month, day) < today {
+        return Err(i18n::t("meet.past"));
+    }
+    Ok(())
+}
+
+fn valid_time(answer: &str) -> Result<(), String> {
+    match parse_time(answer) {
+        Some(_) => Ok(()),
+        None => Err(i18n::tr("meet.bad_time", &[("answer", answer)])),
+    }
+}
+
+fn valid_length(answer: &str) -> Result<(), String> {
+    match minutes(answer) {
+        Some(15..=480) => Ok(()),
+        _ => Err(i18n::t("meet.bad_length")),
+    }
+}
+
+/// "2026-10-21" as (year, month, day), if that day exists.
+fn parse_date(text: &str) -> Option<(u32, u32, u32)> {
+    let mut parts = text
+        .trim()
+        .splitn(3, '-')
+        .map(|part| part.parse::<u32>().ok());
+    let (year, month, day) = (parts.next()??, parts.next()??, parts.next()??);
+    let leap = year % 4 == 0 && (year % 100 != 0 || year % 400 == 0);
+    let days = match month {
+        2 if leap => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        1..=12 => 31,
+        _ => return None,
+    };
+    (year >= 1970 && (1..=days).contains(&day)).then_some((year, month, day))
+}
+
+/// "14:30", "2:30pm" or "2pm" as (hour, minute) on a 24-hour clock.
+fn parse_time(text: &str) -> Option<(u32, u32)> {
+    let text = text.trim().to_lowercase().replace(' ', "");
+    let (clock, meridiem) = match text.strip_suffix("am") {
+        Some(clock) => (clock, Some(false)),
+        None => match text.strip_suffix("pm") {
+            Some(clock) => (clock, Some(true)),
+            None => (text.as_str(), None),
+        },
+    };
+    let (hour, minute) = match clock.split_once(':') {
+        Some((hour, minute)) if minute.len() == 2 => (hour.parse().ok()?, minute.parse().ok()?),
+        Some(_) => return None,
+        None => (clock.parse::<u32>().ok()?, 0),
+    };
+    let hour = match meridiem {
+        Some(_) if !(1..=12).contains(&hour) => return None,
+        Some(false) => hour % 12,
+        Some(true) => hour % 12 + 12,
+        None => hour,
+    };
+    (hour < 24 && minute < 60).then_some((hour, minute))
+}
+
+/// The meeting length, with an empty answer taking the default.
+fn minutes(answer: &str) -> Option<u32> {
+    match answer.trim() {
+        "" => Some(DEFAULT_MINUTES),
+        text => text.parse().ok(),
+    }
+}
+
+fn preview(answers: &[String]) -> Vec<(String, LineStyle)> {
+    let [date, time, length, name, email, topic] = answers else {
+        return vec![];
+    };
+    let (hour, minute) = parse_time(time).unwrap_or_default();
+    let when = i18n::tr(
+        "meet.when_value",
+        &[
+            ("date", date.trim()),
+            ("time", &format!("{:02}:{:02}", hour, minute)),
+            (
+                "minutes",
+                &minutes(length).unwrap_or(DEFAULT_MINUTES).to_string(),
+            ),
+        ],
+    );
+    let to = format!(
+        "{} <{}>",
+        portfolio().profile.name,
+        contact::recipient().unwrap_or_default()
+    );
+    let rows = [
+        ("meet.topic", topic.clone(), LineStyle::Accent),
+        ("meet.when", when, LineStyle::Normal),
+        (
+            "contact.from",
+            format!("{} <{}>", name, email),
+            LineStyle::Normal,
+        ),
+        ("contact.to", to, LineStyle::Normal),
+    ]
+    .map(|(key, value, style)| (i18n::t(key), value, style));
+    let width = rows
+        .iter()
+        .map(|(label, _, _)| label.chars().count())
+        .max()
+        .unwrap_or(0)
+        + 2;
+    let mut lines = vec![(String::new(), LineStyle::Normal)];
+    lines.extend(rows.into_iter().map(|(label, value, style)| {
+        (
+            format!("  {:<width$}{}", format!("{}:", label), value),
+            style,
+        )
+    }));
+    lines
+}
+
+fn finish(_action: &str, answers: &[String]) -> CommandOutput {
+    let ([date, time, length, name, email, topic], Some(owner_email)) =
+        (answers, contact::recipient())
+    else {
+        return CommandOutput::new(vec![], false, false);
+    };
+    let (Some((year, month, day)), Some((hour, minute))) = (parse_date(date), parse_time(time))
+    else {
+        return CommandOutput::new(vec![], false, false);
+    };
+
+    // The visitor answered in their own time zone; the invite is in UTC
+    let start = js_sys::Date::new_with_year_month_day_hr_min(
+        year,
+        month as i32 - 1,
+        day as i32,
+        hour as i32,
+        minute as i32,
+    );
+    let length = minutes(length).unwrap_or(DEFAULT_MINUTES);
+    let end = js_sys::Date::new(&(start.get_time() + length as f64 * 60_000.0).into());
+    let now = js_sys::Date::new_0();
+
+    let owner = &portfolio().profile.name;
+    let host = web_sys::window()
+        .and_then(|window| window.location().host().ok())
+        .unwrap_or_else(|| "localhost".to_string());
+    let invite = Invite {
+        uid: format!("{}-{}@{}", utc_stamp(&now), now.get_milliseconds(), host),
+        stamp: utc_stamp(&now),
+        start: utc_stamp(&start),
+        end: utc_stamp(&end),
+        summary: topic.clone(),
+        description: i18n::tr(
+            "meet.description",
+            &[("name", name), ("email", email), ("host", &host)],
+        ),
+        organizer: (name.clone(), email.clone()),
+        attendee: (owner.clone(), owner_email.to_string()),
+    };
+
+    let filename = "meeting.ics";
+    let bytes = calendar::ics(&invite, owner).into_bytes();
+    match download::trigger_download(filename, download::mime_type(filename), &bytes) {
+        Ok(()) => CommandOutput::new(
+            vec![(
+                format!(
+                    "  {}",
+                    i18n::tr(
+                        "meet.downloading",
+                        &[("file", filename), ("email", owner_email)],
+                    )
+                ),
+                LineStyle::Accent,
+            )],
+            false,
+            false,
+        ),
+        Err(err) => CommandOutput::new(
+            vec![(
+                format!("  {}", i18n::tr("download.failed", &[("reason", &err)])),
+                LineStyle::Error,
+            )],
+            false,
+            false,
+        ),
+    }
+}
+
+/// A moment as an iCalendar UTC time, "YYYYMMDDTHHMMSSZ".
+fn utc_stamp(date: &js_sys::Date) -> String {
+    format!(
+        "{:04}{:02}{:02}T{:02}{:02}{:02}Z",
+        date.get_utc_full_year(),
+        date.get_utc_month() + 1,
+        date.get_utc_date(),
+        date.get_utc_hours(),
+        date.get_utc_minutes(),
+        date.get_utc_seconds()
+    )
+}
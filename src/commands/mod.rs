@@ -1,21 +1,46 @@
+pub mod ask;
+pub mod blog;
+pub mod contact;
+pub mod diff;
+pub mod download;
 pub mod easter_eggs;
+pub mod editor;
 pub mod filesystem;
 pub mod games;
+pub mod image;
+pub mod lang;
+pub mod links;
+pub mod meet;
+pub mod pager;
+pub mod portfolio;
+pub mod prompt;
+pub mod qr;
 pub mod registry;
+pub mod search;
 pub mod theme;
 
-use crate::filesystem::content;
+use crate::i18n;
 use crate::{
-    components::typewriter, filesystem::model::VirtualFs, parser::tokenizer::ParsedCommand,
+    filesystem::model::VirtualFs, parser::tokenizer::ParsedCommand, state::shell::Shell,
     state::theme::ThemeState,
 };
-use registry::all_commands;
+use registry::{all_commands, CommandInfo};
 
 pub struct CommandOutput {
     pub lines: Vec<(String, LineStyle)>,
     pub clear_screen: bool,
     pub typewriter: bool,
-    pub start_game: Option<games::ActiveGame>,
+    pub start_game: Option<Box<dyn games::Game>>,
+    pub full_screen: Option<FullScreen>,
+    pub prompt: Option<prompt::PromptSession>,
+}
+
+/// Modes that take over the whole terminal area and read raw key presses
+/// instead of Enter-terminated lines.
+#[derive(Clone)]
+pub enum FullScreen {
+    Editor(editor::Editor),
+    Pager(pager::Pager),
 }
 
 impl CommandOutput {
@@ -25,53 +50,131 @@ impl CommandOutput {
             clear_screen,
             typewriter,
             start_game: None,
+            full_screen: None,
+            prompt: None,
         }
     }
 
-    pub fn with_game(mut self, game: games::ActiveGame) -> Self {
+    pub fn with_game(mut self, game: Box<dyn games::Game>) -> Self {
         self.start_game = Some(game);
         self
     }
+
+    pub fn with_full_screen(mut self, mode: FullScreen) -> Self {
+        self.full_screen = Some(mode);
+        self
+    }
+
+    pub fn with_prompt(mut self, session: prompt::PromptSession) -> Self {
+        self.prompt = Some(session);
+        self
+    }
 }
 
-#[derive(Clone, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub enum LineStyle {
     Normal,
     Accent,
     Error,
     Muted,
+    Emphasis,
+    Code,
+    /// A line present only in the newer side of a diff
+    Added,
+    /// A line present only in the older side of a diff
+    Removed,
+    /// The whole line is an anchor pointing at the given href
+    Link(String),
+    /// Render the image at this src inline; the line text is its alt text
+    Image(String),
+    /// Normal text with these byte ranges marked, e.g. search matches
+    Highlight(Vec<(usize, usize)>),
+    /// A row of a QR code, drawn in block characters with no line gaps
+    Qr,
+}
+
+impl LineStyle {
+    pub fn css_class(&self) -> &'static str {
+        match self {
+            LineStyle::Normal => "line-normal",
+            LineStyle::Accent => "line-accent",
+            LineStyle::Error => "line-error",
+            LineStyle::Muted => "line-muted",
+            LineStyle::Emphasis => "line-emphasis",
+            LineStyle::Code => "line-code",
+            LineStyle::Added => "line-added",
+            LineStyle::Removed => "line-removed",
+            LineStyle::Link(_) => "line-link",
+            LineStyle::Image(_) => "line-image",
+            LineStyle::Highlight(_) => "line-normal",
+            LineStyle::Qr => "line-qr",
+        }
+    }
 }
 
 pub fn dispatch(
     cmd: &ParsedCommand,
-    fs: &VirtualFs,
-    cwd: &mut String,
+    fs: &mut VirtualFs,
+    shell: &mut Shell,
     theme: &ThemeState,
     history: &[String],
 ) -> CommandOutput {
-    match cmd.command.as_str() {
+    // Commands can also be run by their full path, e.g. /usr/bin/ls
+    let name = cmd
+        .command
+        .strip_prefix("/usr/bin/")
+        .unwrap_or(&cmd.command);
+
+    let output = match name {
         "help" => help_command(),
-        "about" => content_output(content::CMD_ABOUT),
-        "skills" => content_output(content::CMD_SKILLS),
-        "projects" => content_output(content::CMD_PROJECTS),
-        "contact" => content_output(content::CMD_CONTACT),
-        "resume" => content_output(content::CMD_RESUME),
+        "about" => portfolio::about(),
+        "skills" => portfolio::skills(&cmd.args),
+        "projects" => portfolio::projects(fs, &cmd.args),
+        "experience" => portfolio::experience(&cmd.args),
+        "blog" => blog::blog(fs, &cmd.args),
+        "search" => search::search(fs, shell, &cmd.args),
+        "ask" => ask::ask(fs, shell, &cmd.args),
+        "contact" => contact::contact(&cmd.args),
+        "card" => contact::card(&cmd.args),
+        "meet" => meet::meet(),
+        "qr" => qr::qr(&cmd.args),
+        "resume" => portfolio::resume(&cmd.args),
         "clear" => CommandOutput::new(vec![], true, false),
         "" => CommandOutput::new(vec![], false, false),
-        "pwd" => filesystem::pwd(cwd),
-        "cd" => filesystem::cd(fs, cwd, &cmd.args),
-        "ls" => filesystem::ls(fs, cwd, &cmd.args),
-        "cat" => filesystem::cat(fs, cwd, &cmd.args),
-        "tree" => filesystem::tree(fs, cwd, &cmd.args),
+        "pwd" => filesystem::pwd(shell),
+        "cd" => filesystem::cd(fs, shell, &cmd.args),
+        "pushd" => filesystem::pushd(fs, shell, &cmd.args),
+        "popd" => filesystem::popd(fs, shell),
+        "dirs" => filesystem::dirs(shell, &cmd.args),
+        "ls" => filesystem::ls(fs, &shell.cwd, &cmd.args),
+        "cat" => filesystem::cat(fs, &shell.cwd, &cmd.args),
+        "grep" => filesystem::grep(fs, &shell.cwd, &cmd.args),
+        "tree" => filesystem::tree(fs, &shell.cwd, &cmd.args),
+        "diff" => diff::diff(fs, &shell.cwd, &cmd.args),
+        "touch" => filesystem::touch(fs, &shell.cwd, &cmd.args),
+        "mkdir" => filesystem::mkdir(fs, &shell.cwd, &cmd.args),
+        "reset-fs" => filesystem::reset_fs(fs, shell),
+        "nano" | "vi" => editor::nano(fs, &shell.cwd, &cmd.args),
+        "less" | "more" => pager::less(fs, &shell.cwd, &cmd.args),
+        "view" => image::view(fs, &shell.cwd, &cmd.args),
+        "open" => links::open(&cmd.args, &shell.links),
+        "download" => download::download(fs, &shell.cwd, &cmd.args),
+        "tar" => download::tar(fs, &shell.cwd, &cmd.args),
+        "zip" => download::zip(fs, &shell.cwd, &cmd.args),
         "theme" => theme::theme_command(&cmd.args, theme),
+        "lang" => lang::lang(fs, &cmd.args),
         "sudo" => easter_eggs::sudo(&cmd.args),
         "rm" => {
-            // Check if args contain "-rf" patterns
-            let args_str = cmd.args.join(" ");
-            if args_str.contains("-rf") || args_str.contains("-r") {
+            // Wiping the root or home is a joke rather than a real delete
+            let forced = cmd.args.iter().any(|a| a == "-rf" || a == "-fr");
+            let everything = cmd
+                .args
+                .iter()
+                .any(|a| matches!(a.as_str(), "/" | "/*" | "~" | "~/" | "~/*"));
+            if forced && everything {
                 easter_eggs::rm_rf()
             } else {
-                simple_output(vec![("  rm: missing operand", LineStyle::Error)])
+                filesystem::rm(fs, &shell.cwd, &cmd.args)
             }
         }
         "neofetch" => easter_eggs::neofetch(),
@@ -80,47 +183,40 @@ pub fn dispatch(
         "date" => easter_eggs::date(),
         "echo" => easter_eggs::echo(&cmd.args),
         "exit" | "quit" | "logout" => easter_eggs::exit(),
-        "history" => easter_eggs::history_cmd(&history),
-        "ttt" | "tictactoe" => {
-            let game = games::tictactoe::TicTacToe::new();
-            let output = games::tictactoe::TicTacToe::start_output();
-            CommandOutput {
-                lines: output.lines,
-                clear_screen: false,
-                typewriter: false,
-                start_game: Some(games::ActiveGame::TicTacToe(game)),
-            }
-        }
-        "typing" => {
-            let test = games::typing_test::TypingTest::new();
-            let output = test.start_output().lines;
-            CommandOutput {
-                lines: output,
-                clear_screen: false,
-                typewriter: false,
-                start_game: Some(games::ActiveGame::TypingTest(test)),
-            }
-        }
-        unknown => simple_output(vec![
-            (
-                &format!("  Command not found: {}", unknown),
-                LineStyle::Error,
-            ),
-            ("  Type 'help' for available commands.", LineStyle::Muted),
-        ]),
+        "history" => easter_eggs::history_cmd(history),
+        "games" => games::games(),
+        unknown => match games::find(unknown) {
+            Some(game) => games::start(game),
+            None => simple_output(vec![
+                (
+                    format!("  {}", i18n::tr("shell.not_found", &[("name", unknown)])).as_str(),
+                    LineStyle::Error,
+                ),
+                (
+                    format!("  {}", i18n::t("shell.help_hint")).as_str(),
+                    LineStyle::Muted,
+                ),
+            ]),
+        },
+    };
+
+    // `open <n>` counts links in whatever was printed before it
+    if !matches!(name, "" | "open") {
+        shell.links = links::collect(&output.lines);
     }
+    output
 }
 
 fn help_command() -> CommandOutput {
     let mut lines: Vec<(String, LineStyle)> = vec![
         (String::new(), LineStyle::Normal),
-        ("Available commands:".to_string(), LineStyle::Accent),
+        (i18n::t("help.title"), LineStyle::Accent),
         (String::new(), LineStyle::Normal),
     ];
 
     for cmd in all_commands().iter().filter(|c| !c.hidden) {
         lines.push((
-            format!("{:<12} {}", cmd.name, cmd.description),
+            format!("{:<12} {}", cmd.name, description(cmd)),
             LineStyle::Normal,
         ));
     }
@@ -132,9 +228,16 @@ fn help_command() -> CommandOutput {
         clear_screen: false,
         typewriter: false,
         start_game: None,
+        full_screen: None,
+        prompt: None,
     }
 }
 
+/// A command's description in the current language.
+fn description(info: &CommandInfo) -> String {
+    i18n::t_or(&format!("cmd.{}", info.name), info.description)
+}
+
 fn simple_output(content: Vec<(&str, LineStyle)>) -> CommandOutput {
     CommandOutput {
         lines: content
@@ -144,30 +247,7 @@ fn simple_output(content: Vec<(&str, LineStyle)>) -> CommandOutput {
         clear_screen: false,
         typewriter: true,
         start_game: None,
-    }
-}
-
-fn content_output(content: &[(&str, bool)]) -> CommandOutput {
-    CommandOutput {
-        lines: content
-            .iter()
-            .map(|(text, is_accent)| {
-                let style = if *is_accent {
-                    LineStyle::Accent
-                } else if text.trim().is_empty() {
-                    LineStyle::Normal
-                } else if text.trim_start().starts_with("Run ")
-                    || text.trim_start().starts_with("Type ")
-                {
-                    LineStyle::Muted
-                } else {
-                    LineStyle::Normal
-                };
-                (text.to_string(), style)
-            })
-            .collect(),
-        clear_screen: false,
-        typewriter: true,
-        start_game: None,
+        full_screen: None,
+        prompt: None,
     }
 }
@@ -0,0 +1,200 @@
+use crate::commands::{filesystem, CommandOutput, FullScreen, LineStyle};
+use crate::filesystem::model::VirtualFs;
+use crate::i18n;
+use crate::state::viewport;
+
+// Rows taken by the status line and terminal padding
+const CHROME_ROWS: usize = 3;
+
+pub enum PagerAction {
+    None,
+    Quit,
+}
+
+#[derive(Clone)]
+pub struct Pager {
+    pub lines: Vec<(String, LineStyle)>,
+    pub top: usize,
+    pub height: usize,
+    pub query: String,
+    /// Text typed after `/` while the search prompt is open
+    pub search_input: Option<String>,
+    pub message: Option<String>,
+    current_match: Option<usize>,
+}
+
+impl Pager {
+    pub fn new(lines: Vec<(String, LineStyle)>) -> Self {
+        Self {
+            lines,
+            top: 0,
+            height: Self::page_height(),
+            query: String::new(),
+            search_input: None,
+            message: None,
+            current_match: None,
+        }
+    }
+
+    pub fn page_height() -> usize {
+        viewport::rows().saturating_sub(CHROME_ROWS).max(5)
+    }
+
+    /// Open at `line` as if it had been found by searching for `query`.
+    pub fn at_match(mut self, query: &str, line: usize) -> Self {
+        self.query = query.to_string();
+        self.current_match = Some(line);
+        self.scroll_to(line);
+        self
+    }
+
+    /// Whether output of this many lines should be paged automatically.
+    pub fn should_page(line_count: usize) -> bool {
+        line_count > Self::page_height()
+    }
+
+    pub fn handle_key(&mut self, key: &str) -> PagerAction {
+        if let Some(input) = self.search_input.as_mut() {
+            match key {
+                "Enter" => {
+                    self.query = self.search_input.take().unwrap_or_default();
+                    self.search(true, true);
+                }
+                "Escape" => self.search_input = None,
+                "Backspace" => {
+                    if input.pop().is_none() {
+                        self.search_input = None;
+                    }
+                }
+                other if other.chars().count() == 1 => input.push_str(other),
+                _ => {}
+            }
+            return PagerAction::None;
+        }
+
+        self.message = None;
+        match key {
+            "q" | "Q" | "Escape" => return PagerAction::Quit,
+            " " | "f" | "PageDown" => self.scroll_to(self.top + self.height),
+            "b" | "PageUp" => self.scroll_to(self.top.saturating_sub(self.height)),
+            "j" | "Enter" | "ArrowDown" => self.scroll_to(self.top + 1),
+            "k" | "ArrowUp" => self.scroll_to(self.top.saturating_sub(1)),
+            "d" => self.scroll_to(self.top + self.height / 2),
+            "u" => self.scroll_to(self.top.saturating_sub(self.height / 2)),
+            "g" | "Home" => self.scroll_to(0),
+            "G" | "End" => self.scroll_to(self.max_top()),
+            "/" => {
+                self.search_input = Some(String::new());
+                self.current_match = None;
+            }
+            "n" => self.search(true, false),
+            "N" => self.search(false, false),
+            _ => {}
+        }
+        PagerAction::None
+    }
+
+    pub fn at_end(&self) -> bool {
+        self.top >= self.max_top()
+    }
+
+    pub fn status(&self) -> String {
+        if let Some(input) = &self.search_input {
+            return format!("/{}", input);
+        }
+        if let Some(message) = &self.message {
+            return message.clone();
+        }
+        if self.at_end() {
+            return i18n::t("pager.end");
+        }
+        let bottom = (self.top + self.height).min(self.lines.len());
+        let percent = bottom * 100 / self.lines.len().max(1);
+        i18n::tr(
+            "pager.position",
+            &[
+                ("first", &(self.top + 1).to_string()),
+                ("last", &bottom.to_string()),
+                ("total", &self.lines.len().to_string()),
+                ("percent", &percent.to_string()),
+            ],
+        )
+    }
+
+    fn max_top(&self) -> usize {
+        self.lines.len().saturating_sub(self.height)
+    }
+
+    fn scroll_to(&mut self, top: usize) {
+        self.top = top.min(self.max_top());
+    }
+
+    /// Jump to the next (or previous) line matching the current query.
+    /// A fresh search starts from the top of the current page.
+    fn search(&mut self, forward: bool, fresh: bool) {
+        if self.query.is_empty() {
+            self.message = Some(i18n::t("pager.no_pattern"));
+            return;
+        }
+
+        let needle = self.query.to_lowercase();
+        let matches: Vec<usize> = self
+            .lines
+            .iter()
+            .enumerate()
+            .filter(|(_, (text, _))| text.to_lowercase().contains(&needle))
+            .map(|(i, _)| i)
+            .collect();
+
+        let anchor = if fresh { None } else { self.current_match };
+        let found = if forward {
+            let from = anchor.map(|m| m + 1).unwrap_or(self.top);
+            matches.iter().find(|&&i| i >= from).copied()
+        } else {
+            let before = anchor.unwrap_or(self.top);
+            matches.iter().rev().find(|&&i| i < before).copied()
+        };
+
+        match found {
+            Some(line) => {
+                self.current_match = Some(line);
+                self.scroll_to(line);
+                let position = matches.iter().position(|&i| i == line).unwrap_or(0);
+                self.message = Some(i18n::tr(
+                    "pager.match",
+                    &[
+                        ("query", &self.query),
+                        ("n", &(position + 1).to_string()),
+                        ("count", &matches.len().to_string()),
+                    ],
+                ));
+            }
+            None => self.message = Some(i18n::t("pager.not_found")),
+        }
+    }
+}
+
+pub fn less(fs: &VirtualFs, cwd: &str, args: &[String]) -> CommandOutput {
+    if args.is_empty() {
+        return CommandOutput::new(
+            vec![(
+                format!("less: {}", i18n::t("fs.missing_file_operand")),
+                LineStyle::Error,
+            )],
+            false,
+            false,
+        );
+    }
+
+    let output = filesystem::cat(fs, cwd, args);
+    if output
+        .lines
+        .iter()
+        .any(|(_, style)| *style == LineStyle::Error)
+    {
+        return output;
+    }
+
+    CommandOutput::new(vec![], false, false)
+        .with_full_screen(FullScreen::Pager(Pager::new(output.lines)))
+}
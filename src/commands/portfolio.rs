@@ -0,0 +1,560 @@
+use serde::Serialize;
+
+use crate::commands::pager::Pager;
+use crate::commands::{download, image, CommandOutput, FullScreen, LineStyle};
+use crate::filesystem::model::{VirtualFs, HOME};
+use crate::i18n;
+use crate::portfolio::model::{Experience, Project, Skill};
+use crate::portfolio::text::{self, CATEGORIES};
+use crate::portfolio::{portfolio, resume};
+use crate::state::viewport;
+
+pub fn about() -> CommandOutput {
+    let data = portfolio();
+    let profile = &data.profile;
+    let intro = match data.experience.iter().find(|job| job.end.is_none()) {
+        Some(job) => i18n::tr(
+            "about.intro_at",
+            &[
+                ("name", &profile.name),
+                ("role", &job.role),
+                ("company", &job.company),
+            ],
+        ),
+        None => i18n::tr(
+            "about.intro",
+            &[("name", &profile.name), ("title", &profile.title)],
+        ),
+    };
+    output(vec![
+        (String::new(), LineStyle::Normal),
+        (intro, LineStyle::Accent),
+        (profile.tagline.clone(), LineStyle::Normal),
+        (profile.focus.clone(), LineStyle::Normal),
+        (String::new(), LineStyle::Normal),
+        (i18n::t("about.hint_skills"), LineStyle::Muted),
+        (i18n::t("about.hint_experience"), LineStyle::Muted),
+        (String::new(), LineStyle::Normal),
+    ])
+}
+
+pub fn skills(args: &[String]) -> CommandOutput {
+    let portfolio = portfolio();
+    let flag_value = |flag: &str| {
+        args.iter()
+            .position(|a| a == flag)
+            .and_then(|i| args.get(i + 1))
+            .map(|v| v.to_lowercase())
+    };
+    let category = flag_value("--category");
+    let sort = flag_value("--sort");
+    let vertical = args.iter().any(|a| a == "--vertical");
+
+    if let Some(category) = &category {
+        if !CATEGORIES.iter().any(|(key, _)| key == category) {
+            let known: Vec<&str> = CATEGORIES.iter().map(|(key, _)| *key).collect();
+            return error(format!(
+                "skills: {}",
+                i18n::tr(
+                    "skills.no_category",
+                    &[("category", category), ("known", &known.join(", "))],
+                )
+            ));
+        }
+    }
+
+    let mut skills: Vec<&Skill> = portfolio
+        .skills
+        .iter()
+        .filter(|skill| category.as_ref().is_none_or(|c| skill.category == *c))
+        .collect();
+    match sort.as_deref() {
+        None => {}
+        Some("years") => skills.sort_by_key(|skill| std::cmp::Reverse(skill.years)),
+        Some("level") => skills.sort_by_key(|skill| std::cmp::Reverse(skill.level)),
+        Some("name") => skills.sort_by_key(|skill| skill.name.to_lowercase()),
+        Some(other) => {
+            return error(format!(
+                "skills: {}",
+                i18n::tr("skills.bad_sort", &[("sort", other)])
+            ))
+        }
+    }
+
+    let width = viewport::columns().clamp(40, 100);
+    let name_width = skills
+        .iter()
+        .map(|skill| skill.name.chars().count())
+        .max()
+        .unwrap_or(0);
+    let mut lines = vec![(String::new(), LineStyle::Normal)];
+    if vertical {
+        lines.extend(column_chart(&skills, width));
+    } else if sort.is_some() {
+        // A sorted list reads as one ranking rather than per category
+        lines.extend(bar_chart(&skills, name_width, width));
+    } else {
+        for (key, heading) in CATEGORIES {
+            let group: Vec<&Skill> = skills
+                .iter()
+                .copied()
+                .filter(|skill| skill.category == *key)
+                .collect();
+            if !group.is_empty() {
+                lines.push((
+                    i18n::t_or(&format!("skills.category.{}", key), heading),
+                    LineStyle::Accent,
+                ));
+                lines.extend(bar_chart(&group, name_width, width));
+                lines.push((String::new(), LineStyle::Normal));
+            }
+        }
+        lines.pop();
+    }
+
+    lines.push((String::new(), LineStyle::Normal));
+    lines.push((i18n::t("skills.hint"), LineStyle::Muted));
+    lines.push((String::new(), LineStyle::Normal));
+    output(lines)
+}
+
+/// One row per skill: name, a level bar sized to the terminal, and years.
+fn bar_chart(skills: &[&Skill], name_width: usize, width: usize) -> Vec<(String, LineStyle)> {
+    // Indent, name, gaps, "Intermediate" and "10 yrs"
+    let bar_width = width.saturating_sub(name_width + 26).clamp(10, 40);
+
+    skills
+        .iter()
+        .map(|skill| {
+            (
+                format!(
+                    "  {:<name_width$}  {}  {:<12} {}",
+                    skill.name,
+                    text::bar(skill.level.unwrap_or(0), bar_width),
+                    skill.proficiency(),
+                    skill.years.map(text::years).unwrap_or_default()
+                ),
+                LineStyle::Normal,
+            )
+        })
+        .collect()
+}
+
+/// Levels as columns, numbered left to right, with a legend underneath.
+fn column_chart(skills: &[&Skill], width: usize) -> Vec<(String, LineStyle)> {
+    const HEIGHT: usize = 8;
+    const BLOCKS: [char; 9] = [' ', '▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+    // Each level in eighths of a row, so column tops can be partial blocks
+    let eighths: Vec<usize> = skills
+        .iter()
+        .map(|skill| (skill.level.unwrap_or(0).min(100) as usize * HEIGHT * 8 + 50) / 100)
+        .collect();
+
+    let mut lines = Vec::new();
+    for row in (0..HEIGHT).rev() {
+        let top = (row + 1) * 100 / HEIGHT;
+        let axis = if top % 25 == 0 {
+            format!("{:>5} ┤", top)
+        } else {
+            "      │".to_string()
+        };
+        let cells: String = eighths
+            .iter()
+            .map(|&e| {
+                let block = BLOCKS[e.saturating_sub(row * 8).min(8)];
+                format!(" {}{}", block, block)
+            })
+            .collect();
+        lines.push((format!("{}{}", axis, cells), LineStyle::Normal));
+    }
+    lines.push((
+        format!("    0 └{}", "─".repeat(skills.len() * 3 + 1)),
+        LineStyle::Muted,
+    ));
+    let numbers: String = (1..=skills.len()).map(|n| format!("{:>3}", n)).collect();
+    lines.push((format!("       {}", numbers), LineStyle::Muted));
+    lines.push((String::new(), LineStyle::Normal));
+
+    // Legend entries, packed as many to a line as fit
+    let mut legend = String::new();
+    for (i, skill) in skills.iter().enumerate() {
+        let entry = match skill.years {
+            Some(years) => format!("  {}. {} ({})", i + 1, skill.name, text::years(years)),
+            None => format!("  {}. {}", i + 1, skill.name),
+        };
+        if !legend.is_empty() && legend.chars().count() + entry.chars().count() > width {
+            lines.push((std::mem::take(&mut legend), LineStyle::Muted));
+        }
+        legend.push_str(&entry);
+    }
+    lines.push((legend, LineStyle::Muted));
+    lines
+}
+
+pub fn projects(fs: &VirtualFs, args: &[String]) -> CommandOutput {
+    let projects = &portfolio().projects;
+    let flag_value = |flag: &str| {
+        args.iter()
+            .position(|a| a == flag)
+            .and_then(|i| args.get(i + 1))
+            .map(|v| v.to_lowercase())
+    };
+    let tag = flag_value("--tag");
+    let sort = flag_value("--sort");
+    let json = args.iter().any(|a| a == "--json");
+
+    // Anything that isn't a flag or a flag's value picks one project
+    let selector = args.iter().enumerate().find(|(i, a)| {
+        !a.starts_with("--") && (*i == 0 || !matches!(args[i - 1].as_str(), "--tag" | "--sort"))
+    });
+    if let Some((_, selector)) = selector {
+        let found = match selector.parse::<usize>() {
+            Ok(n) => n.checked_sub(1).and_then(|i| projects.get(i)),
+            Err(_) => projects.iter().find(|p| p.slug == selector.to_lowercase()),
+        };
+        return match (found, json) {
+            (Some(project), true) => json_output(project),
+            (Some(project), false) => project_card(fs, project),
+            (None, _) => error(format!(
+                "projects: {}",
+                i18n::tr("projects.no_project", &[("selector", selector)])
+            )),
+        };
+    }
+
+    // Keep each project's position so `projects <n>` still matches the list
+    let mut listed: Vec<(usize, &Project)> = projects
+        .iter()
+        .enumerate()
+        .filter(|(_, p)| tag.as_ref().is_none_or(|tag| p.tags.contains(tag)))
+        .collect();
+    match sort.as_deref() {
+        None => {}
+        Some("date") => listed.sort_by(|a, b| b.1.date.cmp(&a.1.date)),
+        Some("name") => listed.sort_by(|a, b| a.1.name.cmp(&b.1.name)),
+        Some(other) => {
+            return error(format!(
+                "projects: {}",
+                i18n::tr("projects.bad_sort", &[("sort", other)])
+            ))
+        }
+    }
+
+    if json {
+        let selected: Vec<&Project> = listed.iter().map(|(_, p)| *p).collect();
+        return json_output(&selected);
+    }
+
+    if listed.is_empty() {
+        return error(format!(
+            "projects: {}",
+            i18n::tr("projects.no_tagged", &[("tag", &tag.unwrap_or_default())])
+        ));
+    }
+
+    let mut lines = vec![(String::new(), LineStyle::Normal)];
+    for (i, project) in &listed {
+        lines.push((
+            format!("{}. {}  ({})", i + 1, project.name, project.date),
+            LineStyle::Accent,
+        ));
+        lines.push((project.summary.clone(), LineStyle::Normal));
+        lines.push((hashtags(&project.tags), LineStyle::Muted));
+        lines.push((String::new(), LineStyle::Normal));
+    }
+    lines.push((i18n::t("projects.hint"), LineStyle::Muted));
+    lines.push((String::new(), LineStyle::Normal));
+    output(lines)
+}
+
+fn project_card(fs: &VirtualFs, project: &Project) -> CommandOutput {
+    let mut lines = vec![
+        (String::new(), LineStyle::Normal),
+        (project.name.clone(), LineStyle::Accent),
+        ("═".repeat(project.name.chars().count()), LineStyle::Accent),
+        (project.description.clone(), LineStyle::Normal),
+        (String::new(), LineStyle::Normal),
+    ];
+
+    // Labels line up on the longest one in the current language
+    let mut rows = vec![
+        (
+            i18n::t("projects.role"),
+            project.role.clone(),
+            LineStyle::Normal,
+        ),
+        (
+            i18n::t("projects.stack"),
+            project.tech.join(", "),
+            LineStyle::Normal,
+        ),
+        (
+            i18n::t("projects.date"),
+            format!("{} · {}", project.date, project.status),
+            LineStyle::Normal,
+        ),
+        (
+            i18n::t("projects.tags"),
+            hashtags(&project.tags),
+            LineStyle::Normal,
+        ),
+    ];
+    for (key, url) in [
+        ("projects.live", &project.url),
+        ("projects.source", &project.repo),
+    ] {
+        if let Some(url) = url {
+            rows.push((
+                i18n::t(key),
+                text::display_url(url).to_string(),
+                LineStyle::Link(url.clone()),
+            ));
+        }
+    }
+    let width = rows
+        .iter()
+        .map(|(label, _, _)| label.chars().count())
+        .max()
+        .unwrap_or(0)
+        + 2;
+    lines.extend(rows.into_iter().map(|(label, value, style)| {
+        (
+            format!("  {:<width$}{}", format!("{}:", label), value),
+            style,
+        )
+    }));
+
+    if !project.features.is_empty() {
+        lines.push((String::new(), LineStyle::Normal));
+        lines.push((
+            format!("  {}:", i18n::t("projects.features")),
+            LineStyle::Accent,
+        ));
+        for feature in &project.features {
+            lines.push((format!("   • {}", feature), LineStyle::Normal));
+        }
+    }
+
+    if let Some(name) = &project.screenshot {
+        let path = format!("{}/projects/{}/{}", HOME, project.slug, name);
+        let label = format!("~/projects/{}/{}", project.slug, name);
+        let art = image::render_file(fs, &path, &label, &screenshot_args());
+        lines.push((String::new(), LineStyle::Normal));
+        lines.extend(
+            art.lines
+                .into_iter()
+                .filter(|(_, style)| *style != LineStyle::Error),
+        );
+    }
+
+    lines.push((String::new(), LineStyle::Normal));
+    lines.push((
+        i18n::tr("projects.readme_hint", &[("slug", &project.slug)]),
+        LineStyle::Muted,
+    ));
+    lines.push((String::new(), LineStyle::Normal));
+    output(lines)
+}
+
+fn screenshot_args() -> Vec<String> {
+    ["--ascii", "--width", "48"]
+        .iter()
+        .map(|a| a.to_string())
+        .collect()
+}
+
+fn hashtags(tags: &[String]) -> String {
+    tags.iter()
+        .map(|tag| format!("#{}", tag))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn json_output<T: Serialize + ?Sized>(value: &T) -> CommandOutput {
+    let json = serde_json::to_string_pretty(value).unwrap_or_default();
+    output(
+        json.lines()
+            .map(|line| (line.to_string(), LineStyle::Code))
+            .collect(),
+    )
+}
+
+pub fn experience(args: &[String]) -> CommandOutput {
+    let jobs = &portfolio().experience;
+    let today = this_month();
+    let width = viewport::columns().clamp(40, 90) - 7;
+
+    if !args.is_empty() {
+        let query = args.join(" ").to_lowercase();
+        return match jobs
+            .iter()
+            .find(|job| matches_company(&job.company, &query))
+        {
+            Some(job) => experience_detail(job, &today, width),
+            None => error(format!(
+                "experience: {}",
+                i18n::tr("experience.no_match", &[("query", &args.join(" "))])
+            )),
+        };
+    }
+
+    let mut lines = vec![(String::new(), LineStyle::Normal)];
+    let mut total = 0;
+    for (i, job) in jobs.iter().enumerate() {
+        let months = tenure(job, &today);
+        total += months;
+
+        // The rail stops at the oldest role
+        let rail = if i + 1 == jobs.len() { " " } else { "│" };
+        lines.push((
+            format!("  ●  {} · {}", job.role, job.company),
+            LineStyle::Accent,
+        ));
+        lines.push((
+            format!("  {}  {}", rail, dates(job, months)),
+            LineStyle::Muted,
+        ));
+        for line in resume::wrap(&job.summary, width) {
+            lines.push((format!("  {}  {}", rail, line), LineStyle::Normal));
+        }
+        if i + 1 < jobs.len() {
+            lines.push(("  │".to_string(), LineStyle::Normal));
+        }
+    }
+
+    lines.push((String::new(), LineStyle::Normal));
+    lines.push((
+        format!(
+            "  {}",
+            i18n::tr("experience.total", &[("duration", &text::duration(total))])
+        ),
+        LineStyle::Emphasis,
+    ));
+    lines.push((String::new(), LineStyle::Normal));
+    lines.push((i18n::t("experience.hint"), LineStyle::Muted));
+    lines.push((String::new(), LineStyle::Normal));
+    output(lines)
+}
+
+fn experience_detail(job: &Experience, today: &str, width: usize) -> CommandOutput {
+    let heading = format!("{} @ {}", job.role, job.company);
+    let underline = "═".repeat(heading.chars().count());
+    let mut lines = vec![
+        (String::new(), LineStyle::Normal),
+        (heading, LineStyle::Accent),
+        (underline, LineStyle::Accent),
+        (dates(job, tenure(job, today)), LineStyle::Muted),
+        (String::new(), LineStyle::Normal),
+    ];
+
+    for line in resume::wrap(&job.summary, width) {
+        lines.push((line, LineStyle::Normal));
+    }
+    lines.push((String::new(), LineStyle::Normal));
+    for highlight in &job.highlights {
+        for (i, line) in resume::wrap(highlight, width - 4).into_iter().enumerate() {
+            let bullet = if i == 0 { "•" } else { " " };
+            lines.push((format!("  {} {}", bullet, line), LineStyle::Normal));
+        }
+    }
+    lines.push((String::new(), LineStyle::Normal));
+    output(lines)
+}
+
+/// Months in a role, up to this month for the current one.
+fn tenure(job: &Experience, today: &str) -> u32 {
+    text::months_between(&job.start, job.end.as_deref().unwrap_or(today)).unwrap_or(0)
+}
+
+/// "Jan 2023 – present · 3 yrs 10 mos", without the length while the
+/// dates aren't filled in.
+fn dates(job: &Experience, months: u32) -> String {
+    let period = format!(
+        "{} – {}",
+        text::month_label(&job.start),
+        job.end
+            .as_deref()
+            .map(text::month_label)
+            .unwrap_or_else(|| i18n::t("experience.present")),
+    );
+    if months == 0 {
+        return period;
+    }
+    format!("{} · {}", period, text::duration(months))
+}
+
+/// A company matches by any part of its name, or by its initials ("cnh").
+fn matches_company(company: &str, query: &str) -> bool {
+    let initials: String = company
+        .split_whitespace()
+        .filter_map(|word| word.chars().next())
+        .collect();
+    company.to_lowercase().contains(query) || initials.to_lowercase() == query
+}
+
+/// The current month as "YYYY-MM".
+pub fn this_month() -> String {
+    let now = js_sys::Date::new_0();
+    format!("{:04}-{:02}", now.get_full_year(), now.get_month() + 1)
+}
+
+pub fn resume(args: &[String]) -> CommandOutput {
+    let data = portfolio();
+
+    let Some(i) = args.iter().position(|a| a == "--download") else {
+        // Read it page by page, and keep a copy in the scrollback afterwards
+        let lines = resume::terminal(data, viewport::columns().clamp(40, 90));
+        return CommandOutput::new(lines.clone(), false, false)
+            .with_full_screen(FullScreen::Pager(Pager::new(lines)));
+    };
+
+    let format = args.get(i + 1).map(|f| f.to_lowercase());
+    let bytes = match format.as_deref().unwrap_or("pdf") {
+        "md" => resume::markdown(data).into_bytes(),
+        "txt" => resume::plain_text(data).into_bytes(),
+        "html" => resume::html(data).into_bytes(),
+        "pdf" => resume::pdf(data),
+        other => {
+            return error(format!(
+                "resume: {}",
+                i18n::tr("resume.bad_format", &[("format", other)])
+            ))
+        }
+    };
+
+    let filename = format!(
+        "{}-resume.{}",
+        data.profile.name.to_lowercase().replace(' ', "-"),
+        format.as_deref().unwrap_or("pdf")
+    );
+    match download::trigger_download(&filename, download::mime_type(&filename), &bytes) {
+        Ok(()) => CommandOutput::new(
+            vec![(
+                format!("  {}", i18n::tr("download.started", &[("file", &filename)])),
+                LineStyle::Accent,
+            )],
+            false,
+            false,
+        ),
+        Err(err) => error(format!(
+            "  {}",
+            i18n::tr("download.failed", &[("reason", &err)])
+        )),
+    }
+}
+
+fn output(lines: Vec<(String, LineStyle)>) -> CommandOutput {
+    CommandOutput {
+        lines,
+        clear_screen: false,
+        typewriter: true,
+        start_game: None,
+        full_screen: None,
+        prompt: None,
+    }
+}
+
+fn error(message: String) -> CommandOutput {
+    CommandOutput::new(vec![(message, LineStyle::Error)], false, false)
+}
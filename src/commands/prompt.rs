@@ -0,0 +1,154 @@
+use crate::commands::{CommandOutput, LineStyle};
+use crate::i18n;
+
+/// One question in a prompt session.
+pub struct Field {
+    /// Catalog key of the question
+    pub label: &'static str,
+    /// Keep reading lines until an empty one, e.g. for a message body
+    pub multiline: bool,
+    pub validate: fn(&str) -> Result<(), String>,
+}
+
+/// What a session's answers are for, supplied by the command that starts
+/// it: the questions, a preview of the result, and what can be done with it.
+#[derive(Clone, Copy)]
+pub struct Purpose {
+    pub fields: &'static [Field],
+    pub preview: fn(&[String]) -> Vec<(String, LineStyle)>,
+    /// Keys of the actions offered after the preview, with the catalog
+    /// keys of their descriptions
+    pub actions: &'static [(&'static str, &'static str)],
+    pub finish: fn(&str, &[String]) -> CommandOutput,
+}
+
+/// A conversation read one input line at a time. While it runs, the shell
+/// prompt is replaced by the current question.
+#[derive(Clone)]
+pub struct PromptSession {
+    purpose: Purpose,
+    answers: Vec<String>,
+    /// Lines typed so far for a multiline answer
+    draft: Vec<String>,
+    finished: bool,
+}
+
+impl PromptSession {
+    pub fn new(purpose: Purpose) -> Self {
+        Self {
+            purpose,
+            answers: Vec::new(),
+            draft: Vec::new(),
+            finished: false,
+        }
+    }
+
+    /// The text shown in place of the shell prompt.
+    pub fn question(&self) -> String {
+        match self.purpose.fields.get(self.answers.len()) {
+            Some(_) if !self.draft.is_empty() => "... ".to_string(),
+            Some(field) => format!("{}: ", i18n::t(field.label)),
+            None => "> ".to_string(),
+        }
+    }
+
+    pub fn handle_input(&mut self, input: &str) -> CommandOutput {
+        let fields = self.purpose.fields;
+        let Some(field) = fields.get(self.answers.len()) else {
+            return self.choose(input.trim());
+        };
+
+        let input = input.trim();
+        if field.multiline && !input.is_empty() {
+            self.draft.push(input.to_string());
+            return output(vec![]);
+        }
+
+        let answer = if field.multiline {
+            std::mem::take(&mut self.draft).join("\n")
+        } else {
+            input.to_string()
+        };
+        if let Err(message) = (field.validate)(&answer) {
+            return error(format!("  {}", message));
+        }
+        self.answers.push(answer);
+
+        if self.answers.len() < fields.len() {
+            return output(vec![]);
+        }
+        let mut lines = (self.purpose.preview)(&self.answers);
+        lines.push((String::new(), LineStyle::Normal));
+        lines.push((self.choices(), LineStyle::Muted));
+        output(lines)
+    }
+
+    /// Handle the answer to "what now?" once every field is filled in.
+    fn choose(&mut self, input: &str) -> CommandOutput {
+        let key = input.to_lowercase();
+        match key.as_str() {
+            "e" | "edit" => {
+                self.answers.clear();
+                output(vec![(
+                    format!("  {}", i18n::t("prompt.starting_over")),
+                    LineStyle::Muted,
+                )])
+            }
+            "q" | "quit" => {
+                self.finished = true;
+                output(vec![(
+                    format!("  {}", i18n::t("prompt.cancelled")),
+                    LineStyle::Muted,
+                )])
+            }
+            _ => {
+                let action = self
+                    .purpose
+                    .actions
+                    .iter()
+                    .find(|(action, _)| *action == key);
+                match action {
+                    Some((action, _)) => {
+                        self.finished = true;
+                        (self.purpose.finish)(action, &self.answers)
+                    }
+                    None => error(format!("  {}{}", i18n::t("prompt.choose"), self.choices())),
+                }
+            }
+        }
+    }
+
+    fn choices(&self) -> String {
+        let actions = self.purpose.actions.iter().copied();
+        let all = actions.chain([("e", "prompt.edit"), ("q", "prompt.quit")]);
+        let choices: Vec<String> = all
+            .map(|(key, description)| format!("[{}] {}", key, i18n::t(description)))
+            .collect();
+        format!("  {}", choices.join("   "))
+    }
+
+    /// Abandon the session, e.g. on Escape.
+    pub fn cancel(&mut self) -> CommandOutput {
+        self.choose("q")
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.finished
+    }
+}
+
+pub fn required(answer: &str) -> Result<(), String> {
+    if answer.trim().is_empty() {
+        Err(i18n::t("prompt.required"))
+    } else {
+        Ok(())
+    }
+}
+
+fn output(lines: Vec<(String, LineStyle)>) -> CommandOutput {
+    CommandOutput::new(lines, false, false)
+}
+
+fn error(message: String) -> CommandOutput {
+    CommandOutput::new(vec![(message, LineStyle::Error)], false, false)
+}
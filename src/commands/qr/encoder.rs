@@ -0,0 +1,466 @@
+/// Error correction levels, weakest first. Each recovers from roughly 7%,
+/// 15%, 25% and 30% of the code being damaged.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Ecc {
+    Low,
+    Medium,
+    Quartile,
+    High,
+}
+
+impl Ecc {
+    fn index(self) -> usize {
+        self as usize
+    }
+
+    /// The two bits identifying the level in the format information.
+    fn format_bits(self) -> u32 {
+        match self {
+            Ecc::Low => 1,
+            Ecc::Medium => 0,
+            Ecc::Quartile => 3,
+            Ecc::High => 2,
+        }
+    }
+}
+
+// Indexed by [level][version]; version 0 is unused
+const ECC_CODEWORDS_PER_BLOCK: [[u8; 41]; 4] = [
+    [
+        0, 7, 10, 15, 20, 26, 18, 20, 24, 30, 18, 20, 24, 26, 30, 22, 24, 28, 30, 28, 28, 28, 28,
+        30, 30, 26, 28, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30,
+    ],
+    [
+        0, 10, 16, 26, 18, 24, 16, 18, 22, 22, 26, 30, 22, 22, 24, 24, 28, 28, 26, 26, 26, 26, 28,
+        28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28, 28,
+    ],
+    [
+        0, 13, 22, 18, 26, 18, 24, 18, 22, 20, 24, 28, 26, 24, 20, 30, 24, 28, 28, 26, 30, 28, 30,
+        30, 30, 30, 28, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30,
+    ],
+    [
+        0, 17, 28, 22, 16, 22, 28, 26, 26, 24, 28, 24, 28, 22, 24, 24, 30, 28, 28, 26, 28, 30, 24,
+        30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30, 30,
+    ],
+];
+
+const ERROR_CORRECTION_BLOCKS: [[u8; 41]; 4] = [
+    [
+        0, 1, 1, 1, 1, 1, 2, 2, 2, 2, 4, 4, 4, 4, 4, 6, 6, 6, 6, 7, 8, 8, 9, 9, 10, 12, 12, 12, 13,
+        14, 15, 16, 17, 18, 19, 19, 20, 21, 22, 24, 25,
+    ],
+    [
+        0, 1, 1, 1, 2, 2, 4, 4, 4, 5, 5, 5, 8, 9, 9, 10, 10, 11, 13, 14, 16, 17, 17, 18, 20, 21,
+        23, 25, 26, 28, 29, 31, 33, 35, 37, 38, 40, 43, 45, 47, 49,
+    ],
+    [
+        0, 1, 1, 2, 2, 4, 4, 6, 6, 8, 8, 8, 10, 12, 16, 12, 17, 16, 18, 21, 20, 23, 23, 25, 27, 29,
+        34, 34, 35, 38, 40, 43, 45, 48, 51, 53, 56, 59, 62, 65, 68,
+    ],
+    [
+        0, 1, 1, 2, 4, 4, 4, 5, 6, 8, 8, 11, 11, 16, 16, 18, 16, 19, 21, 25, 25, 25, 34, 30, 32,
+        35, 37, 40, 42, 45, 48, 51, 54, 57, 60, 63, 66, 70, 74, 77, 81,
+    ],
+];
+
+/// A QR code: a square grid of dark and light modules.
+pub struct QrCode {
+    size: usize,
+    modules: Vec<bool>,
+    /// Finder, timing, alignment and format modules, which masks skip
+    function: Vec<bool>,
+}
+
+impl QrCode {
+    /// Encode bytes in byte mode at the smallest version that fits, raising
+    /// the error correction above `min_ecc` when that costs no extra size.
+    /// `None` if the data is too long for any version.
+    pub fn encode(data: &[u8], min_ecc: Ecc) -> Option<Self> {
+        let fits = |version: usize, ecc: Ecc| {
+            let count_bits = if version <= 9 { 8 } else { 16 };
+            data.len() < (1 << count_bits)
+                && 4 + count_bits + data.len() * 8 <= data_codewords(version, ecc) * 8
+        };
+        let version = (1..=40).find(|&v| fits(v, min_ecc))?;
+        let ecc = [Ecc::High, Ecc::Quartile, Ecc::Medium]
+            .into_iter()
+            .find(|&ecc| ecc.index() > min_ecc.index() && fits(version, ecc))
+            .unwrap_or(min_ecc);
+
+        // Mode indicator, character count, then the bytes themselves
+        let mut bits = BitBuffer::default();
+        bits.push(0b0100, 4);
+        bits.push(data.len() as u32, if version <= 9 { 8 } else { 16 });
+        for &byte in data {
+            bits.push(byte as u32, 8);
+        }
+
+        // Terminate, pad to a byte boundary, then fill with alternating bytes
+        let capacity = data_codewords(version, ecc) * 8;
+        bits.push(0, (capacity - bits.0.len()).min(4));
+        bits.push(0, (8 - bits.0.len() % 8) % 8);
+        for pad in [0xEC, 0x11].into_iter().cycle() {
+            if bits.0.len() >= capacity {
+                break;
+            }
+            bits.push(pad, 8);
+        }
+
+        let codewords: Vec<u8> = bits
+            .0
+            .chunks(8)
+            .map(|byte| byte.iter().fold(0, |acc, &bit| (acc << 1) | bit as u8))
+            .collect();
+
+        let size = version * 4 + 17;
+        let mut qr = QrCode {
+            size,
+            modules: vec![false; size * size],
+            function: vec![false; size * size],
+        };
+        qr.draw_function_patterns(version);
+        qr.draw_codewords(&add_ecc_and_interleave(&codewords, version, ecc));
+
+        // Keep whichever mask leaves the fewest confusing patterns
+        let best = (0..8)
+            .min_by_key(|&mask| {
+                qr.apply_mask(mask);
+                qr.draw_format_bits(ecc, mask);
+                let penalty = qr.penalty();
+                qr.apply_mask(mask);
+                penalty
+            })
+            .unwrap_or(0);
+        qr.apply_mask(best);
+        qr.draw_format_bits(ecc, best);
+        Some(qr)
+    }
+
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Whether the module at column `x`, row `y` is dark.
+    pub fn get(&self, x: usize, y: usize) -> bool {
+        self.modules[y * self.size + x]
+    }
+
+    fn set_function(&mut self, x: usize, y: usize, dark: bool) {
+        self.modules[y * self.size + x] = dark;
+        self.function[y * self.size + x] = true;
+    }
+
+    fn draw_function_patterns(&mut self, version: usize) {
+        let size = self.size;
+        for i in 0..size {
+            self.set_function(6, i, i % 2 == 0);
+            self.set_function(i, 6, i % 2 == 0);
+        }
+
+        self.draw_finder(3, 3);
+        self.draw_finder(size - 4, 3);
+        self.draw_finder(3, size - 4);
+
+        let positions = alignment_positions(version);
+        let last = positions.len().saturating_sub(1);
+        for (i, &x) in positions.iter().enumerate() {
+            for (j, &y) in positions.iter().enumerate() {
+                // The three corners already hold finder patterns
+                if (i, j) != (0, 0) && (i, j) != (0, last) && (i, j) != (last, 0) {
+                    self.draw_alignment(x, y);
+                }
+            }
+        }
+
+        // Reserve the format areas now; the bits are drawn once a mask is chosen
+        self.draw_format_bits(Ecc::Low, 0);
+        self.draw_version(version);
+    }
+
+    /// A 7x7 finder centred on (x, y), with its light separator.
+    fn draw_finder(&mut self, x: usize, y: usize) {
+        for dy in -4..=4_i32 {
+            for dx in -4..=4_i32 {
+                let (mx, my) = (x as i32 + dx, y as i32 + dy);
+                if mx < 0 || my < 0 || mx >= self.size as i32 || my >= self.size as i32 {
+                    continue;
+                }
+                let distance = dx.abs().max(dy.abs());
+                self.set_function(mx as usize, my as usize, distance != 2 && distance != 4);
+            }
+        }
+    }
+
+    fn draw_alignment(&mut self, x: usize, y: usize) {
+        for dy in -2..=2_i32 {
+            for dx in -2..=2_i32 {
+                let dark = dx.abs().max(dy.abs()) != 1;
+                self.set_function((x as i32 + dx) as usize, (y as i32 + dy) as usize, dark);
+            }
+        }
+    }
+
+    fn draw_format_bits(&mut self, ecc: Ecc, mask: usize) {
+        let data = ecc.format_bits() << 3 | mask as u32;
+        let mut remainder = data;
+        for _ in 0..10 {
+            remainder = (remainder << 1) ^ ((remainder >> 9) * 0x537);
+        }
+        let bits = (data << 10 | remainder) ^ 0x5412;
+        let bit = |i: usize| (bits >> i) & 1 != 0;
+
+        // Around the top-left finder
+        for i in 0..6 {
+            self.set_function(8, i, bit(i));
+        }
+        self.set_function(8, 7, bit(6));
+        self.set_function(8, 8, bit(7));
+        self.set_function(7, 8, bit(8));
+        for i in 9..15 {
+            self.set_function(14 - i, 8, bit(i));
+        }
+
+        // Split between the other two finders
+        let size = self.size;
+        for i in 0..8 {
+            self.set_function(size - 1 - i, 8, bit(i));
+        }
+        for i in 8..15 {
+            self.set_function(8, size - 15 + i, bit(i));
+        }
+        self.set_function(8, size - 8, true);
+    }
+
+    fn draw_version(&mut self, version: usize) {
+        if version < 7 {
+            return;
+        }
+        let mut remainder = version as u32;
+        for _ in 0..12 {
+            remainder = (remainder << 1) ^ ((remainder >> 11) * 0x1F25);
+        }
+        let bits = (version as u32) << 12 | remainder;
+        for i in 0..18 {
+            let dark = (bits >> i) & 1 != 0;
+            let (a, b) = (self.size - 11 + i % 3, i / 3);
+            self.set_function(a, b, dark);
+            self.set_function(b, a, dark);
+        }
+    }
+
+    /// Fill the remaining modules in the zigzag order the standard uses:
+    /// two columns at a time, from the right, alternately up and down.
+    fn draw_codewords(&mut self, data: &[u8]) {
+        let size = self.size;
+        let mut i = 0;
+        let mut right = size - 1;
+        loop {
+            // The vertical timing pattern's column is skipped entirely
+            if right == 6 {
+                right = 5;
+            }
+            let upward = (right + 1) & 2 == 0;
+            for vert in 0..size {
+                let y = if upward { size - 1 - vert } else { vert };
+                for x in [right, right - 1] {
+                    if !self.function[y * size + x] && i < data.len() * 8 {
+                        self.modules[y * size + x] = (data[i / 8] >> (7 - i % 8)) & 1 != 0;
+                        i += 1;
+                    }
+                }
+            }
+            if right < 3 {
+                break;
+            }
+            right -= 2;
+        }
+    }
+
+    /// Flip data modules by one of the eight mask patterns. Applying the
+    /// same mask again undoes it.
+    fn apply_mask(&mut self, mask: usize) {
+        for y in 0..self.size {
+            for x in 0..self.size {
+                let flip = match mask {
+                    0 => (x + y) % 2 == 0,
+                    1 => y % 2 == 0,
+                    2 => x % 3 == 0,
+                    3 => (x + y) % 3 == 0,
+                    4 => (x / 3 + y / 2) % 2 == 0,
+                    5 => x * y % 2 + x * y % 3 == 0,
+                    6 => (x * y % 2 + x * y % 3) % 2 == 0,
+                    _ => ((x + y) % 2 + x * y % 3) % 2 == 0,
+                };
+                let i = y * self.size + x;
+                if flip && !self.function[i] {
+                    self.modules[i] ^= true;
+                }
+            }
+        }
+    }
+
+    /// Score the patterns scanners find hard to read: long runs, 2x2
+    /// blocks, finder lookalikes, and an uneven dark/light balance.
+    fn penalty(&self) -> usize {
+        let size = self.size;
+        let mut penalty = 0;
+        let lines = (0..size).flat_map(|i| {
+            [
+                (0..size).map(|j| self.get(j, i)).collect::<Vec<_>>(),
+                (0..size).map(|j| self.get(i, j)).collect::<Vec<_>>(),
+            ]
+        });
+        for line in lines {
+            for run in line.chunk_by(|a, b| a == b) {
+                if run.len() >= 5 {
+                    penalty += run.len() - 2;
+                }
+            }
+            let finder = [true, false, true, true, true, false, true];
+            for window in line.windows(11) {
+                if window[..7] == finder && window[7..].iter().all(|&dark| !dark)
+                    || window[4..] == finder && window[..4].iter().all(|&dark| !dark)
+                {
+                    penalty += 40;
+                }
+            }
+        }
+
+        for y in 0..size - 1 {
+            for x in 0..size - 1 {
+                let dark = self.get(x, y);
+                if dark == self.get(x + 1, y)
+                    && dark == self.get(x, y + 1)
+                    && dark == self.get(x + 1, y + 1)
+                {
+                    penalty += 3;
+                }
+            }
+        }
+
+        let dark = self.modules.iter().filter(|&&dark| dark).count();
+        let percent = dark * 100 / self.modules.len();
+        penalty + percent.abs_diff(50) / 5 * 10
+    }
+}
+
+#[derive(Default)]
+struct BitBuffer(Vec<bool>);
+
+impl BitBuffer {
+    /// Append the low `count` bits of `value`, most significant first.
+    fn push(&mut self, value: u32, count: usize) {
+        self.0
+            .extend((0..count).rev().map(|i| (value >> i) & 1 != 0));
+    }
+}
+
+/// Modules left for data and error correction once every function pattern
+/// is drawn.
+fn raw_data_modules(version: usize) -> usize {
+    let mut modules = (16 * version + 128) * version + 64;
+    if version >= 2 {
+        let alignments = version / 7 + 2;
+        modules -= (25 * alignments - 10) * alignments - 55;
+        if version >= 7 {
+            modules -= 36;
+        }
+    }
+    modules
+}
+
+fn data_codewords(version: usize, ecc: Ecc) -> usize {
+    let level = ecc.index();
+    raw_data_modules(version) / 8
+        - ECC_CODEWORDS_PER_BLOCK[level][version] as usize
+            * ERROR_CORRECTION_BLOCKS[level][version] as usize
+}
+
+/// Centres of the alignment patterns along each axis.
+fn alignment_positions(version: usize) -> Vec<usize> {
+    if version == 1 {
+        return vec![];
+    }
+    let count = version / 7 + 2;
+    let step = (version * 8 + count * 3 + 5) / (count * 4 - 4) * 2;
+    let size = version * 4 + 17;
+    let mut positions: Vec<usize> = (0..count - 1).map(|i| size - 7 - i * step).collect();
+    positions.push(6);
+    positions.reverse();
+    positions
+}
+
+/// Split the data into blocks, add Reed-Solomon error correction to each,
+/// and interleave them so a damaged patch spreads across blocks.
+fn add_ecc_and_interleave(data: &[u8], version: usize, ecc: Ecc) -> Vec<u8> {
+    let level = ecc.index();
+    let blocks = ERROR_CORRECTION_BLOCKS[level][version] as usize;
+    let ecc_len = ECC_CODEWORDS_PER_BLOCK[level][version] as usize;
+    let raw_codewords = raw_data_modules(version) / 8;
+    let short_blocks = blocks - raw_codewords % blocks;
+    let short_len = raw_codewords / blocks;
+
+    let divisor = reed_solomon_divisor(ecc_len);
+    let mut split = Vec::with_capacity(blocks);
+    let mut start = 0;
+    for i in 0..blocks {
+        let len = short_len - ecc_len + usize::from(i >= short_blocks);
+        let mut block = data[start..start + len].to_vec();
+        start += len;
+        let remainder = reed_solomon_remainder(&block, &divisor);
+        // A placeholder keeps the columns lined up; it's skipped below
+        if i < short_blocks {
+            block.push(0);
+        }
+        block.extend(remainder);
+        split.push(block);
+    }
+
+    let mut result = Vec::with_capacity(raw_codewords);
+    for i in 0..split[0].len() {
+        for (j, block) in split.iter().enumerate() {
+            if i != short_len - ecc_len || j >= short_blocks {
+                result.push(block[i]);
+            }
+        }
+    }
+    result
+}
+
+fn reed_solomon_divisor(degree: usize) -> Vec<u8> {
+    let mut result = vec![0; degree];
+    result[degree - 1] = 1;
+    let mut root = 1;
+    for _ in 0..degree {
+        for j in 0..degree {
+            result[j] = gf_multiply(result[j], root);
+            if j + 1 < degree {
+                result[j] ^= result[j + 1];
+            }
+        }
+        root = gf_multiply(root, 0x02);
+    }
+    result
+}
+
+fn reed_solomon_remainder(data: &[u8], divisor: &[u8]) -> Vec<u8> {
+    let mut result = vec![0; divisor.len()];
+    for &byte in data {
+        let factor = byte ^ result.remove(0);
+        result.push(0);
+        for (r, &d) in result.iter_mut().zip(divisor) {
+            *r ^= gf_multiply(d, factor);
+        }
+    }
+    result
+}
+
+/// Multiplication in GF(2^8) modulo x^8 + x^4 + x^3 + x^2 + 1.
+fn gf_multiply(x: u8, y: u8) -> u8 {
+    let mut z: u32 = 0;
+    for i in (0..8).rev() {
+        z = (z << 1) ^ ((z >> 7) * 0x11D);
+        z ^= ((y as u32 >> i) & 1) * x as u32;
+    }
+    z as u8
+}
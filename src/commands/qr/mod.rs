@@ -0,0 +1,142 @@
+pub mod encoder;
+
+use crate::commands::{CommandOutput, LineStyle};
+use crate::i18n;
+use crate::portfolio::portfolio;
+use crate::state::viewport;
+use encoder::{Ecc, QrCode};
+
+/// Light modules around the code; scanners want at least four.
+const QUIET_ZONE: usize = 4;
+
+pub fn qr(args: &[String]) -> CommandOutput {
+    let (data, caption) = match args {
+        [] => {
+            return error(i18n::tr(
+                "shell.usage",
+                &[("usage", "qr <text|url> | qr contact [link]")],
+            ))
+        }
+        [first, rest @ ..] if first == "contact" => match contact_data(rest) {
+            Ok(found) => found,
+            Err(message) => return error(message),
+        },
+        _ => {
+            let text = args.join(" ");
+            (text.clone(), text)
+        }
+    };
+
+    let Some(code) = QrCode::encode(data.as_bytes(), Ecc::Medium) else {
+        return error(format!("qr: {}", i18n::t("qr.too_long")));
+    };
+
+    let mut lines = vec![(String::new(), LineStyle::Normal)];
+    lines.extend(render(&code).into_iter().map(|row| (row, LineStyle::Qr)));
+    lines.push((String::new(), LineStyle::Normal));
+    lines.push((format!("  {}", caption), LineStyle::Muted));
+    if code.size() + QUIET_ZONE * 2 + 2 > viewport::columns() {
+        lines.push((format!("  {}", i18n::t("qr.too_narrow")), LineStyle::Muted));
+    }
+    lines.push((String::new(), LineStyle::Normal));
+    CommandOutput::new(lines, false, false)
+}
+
+/// What `qr contact` encodes: one named link, or a contact card with all
+/// of them that phones offer to save.
+fn contact_data(args: &[String]) -> Result<(String, String), String> {
+    let data = portfolio();
+    let links: Vec<_> = data
+        .contact
+        .iter()
+        .filter_map(|method| Some((method, method.url.as_deref()?)))
+        .collect();
+
+    if let Some(wanted) = args.first() {
+        let Some((method, url)) = links
+            .iter()
+            .find(|(method, _)| method.label.eq_ignore_ascii_case(wanted))
+        else {
+            let labels: Vec<_> = links
+                .iter()
+                .map(|(method, _)| method.label.to_lowercase())
+                .collect();
+            return Err(format!(
+                "qr: {}",
+                i18n::tr(
+                    "qr.no_link",
+                    &[("link", wanted), ("known", &labels.join(", "))],
+                )
+            ));
+        };
+        return Ok((
+            url.to_string(),
+            format!("{}: {}", method.label, method.value),
+        ));
+    }
+
+    let profile = &data.profile;
+    let family = profile
+        .name
+        .strip_prefix(&profile.first_name)
+        .unwrap_or_default()
+        .trim();
+    let mut card = format!(
+        "MECARD:N:{},{};",
+        mecard_escape(family),
+        mecard_escape(&profile.first_name)
+    );
+    for (_, url) in &links {
+        match url.strip_prefix("mailto:") {
+            Some(email) => card.push_str(&format!("EMAIL:{};", mecard_escape(email))),
+            None => card.push_str(&format!("URL:{};", mecard_escape(url))),
+        }
+    }
+    card.push(';');
+    Ok((
+        card,
+        i18n::tr("qr.card_caption", &[("name", &profile.first_name)]),
+    ))
+}
+
+/// MECARD values escape the characters it uses as separators.
+fn mecard_escape(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(':', "\\:")
+        .replace(',', "\\,")
+}
+
+/// Draw the code two module rows to a line with half blocks. The light
+/// modules are the ones drawn, in the theme's text color, so on the dark
+/// terminal background the code reads dark-on-light the way scanners
+/// expect.
+fn render(code: &QrCode) -> Vec<String> {
+    let size = code.size() + QUIET_ZONE * 2;
+    let light = |x: usize, y: usize| {
+        if y >= size {
+            return false;
+        }
+        let inside = QUIET_ZONE..QUIET_ZONE + code.size();
+        !(inside.contains(&x) && inside.contains(&y) && code.get(x - QUIET_ZONE, y - QUIET_ZONE))
+    };
+
+    (0..size)
+        .step_by(2)
+        .map(|y| {
+            let row: String = (0..size)
+                .map(|x| match (light(x, y), light(x, y + 1)) {
+                    (true, true) => '█',
+                    (true, false) => '▀',
+                    (false, true) => '▄',
+                    (false, false) => ' ',
+                })
+                .collect();
+            format!("  {}", row)
+        })
+        .collect()
+}
+
+fn error(message: String) -> CommandOutput {
+    CommandOutput::new(vec![(message, LineStyle::Error)], false, false)
+}
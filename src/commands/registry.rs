@@ -1,3 +1,5 @@
+use crate::commands::games;
+
 pub struct CommandInfo {
     pub name: &'static str,
     pub description: &'static str,
@@ -6,7 +8,7 @@ pub struct CommandInfo {
 }
 
 pub fn all_commands() -> Vec<CommandInfo> {
-    vec![
+    let mut commands = vec![
         CommandInfo {
             name: "help",
             description: "Show available commands",
@@ -22,25 +24,67 @@ pub fn all_commands() -> Vec<CommandInfo> {
         CommandInfo {
             name: "skills",
             description: "View my technical skills",
-            usage: "skills",
+            usage: "skills [--sort years|level|name] [--category <name>] [--vertical]",
             hidden: false,
         },
         CommandInfo {
             name: "projects",
             description: "Browse my projects",
-            usage: "projects",
+            usage: "projects [n | slug] [--tag <tag>] [--sort date|name] [--json]",
+            hidden: false,
+        },
+        CommandInfo {
+            name: "experience",
+            description: "My career timeline",
+            usage: "experience [company]",
+            hidden: false,
+        },
+        CommandInfo {
+            name: "blog",
+            description: "Read my blog posts",
+            usage: "blog [ls [tag] | read <slug> | tags | search <query> | rss]",
+            hidden: false,
+        },
+        CommandInfo {
+            name: "search",
+            description: "Search everything on the site",
+            usage: "search <query> | search --open <n>",
+            hidden: false,
+        },
+        CommandInfo {
+            name: "ask",
+            description: "Ask me a question",
+            usage: "ask <question>",
             hidden: false,
         },
         CommandInfo {
             name: "contact",
             description: "Get my contact information",
-            usage: "contact",
+            usage: "contact [--write]",
+            hidden: false,
+        },
+        CommandInfo {
+            name: "card",
+            description: "Save my contact card",
+            usage: "card [--download]",
+            hidden: false,
+        },
+        CommandInfo {
+            name: "meet",
+            description: "Propose a meeting",
+            usage: "meet",
+            hidden: false,
+        },
+        CommandInfo {
+            name: "qr",
+            description: "Show a QR code to scan with your phone",
+            usage: "qr <text|url> | qr contact [link]",
             hidden: false,
         },
         CommandInfo {
             name: "resume",
             description: "View my resume",
-            usage: "resume",
+            usage: "resume [--download md|txt|html|pdf]",
             hidden: false,
         },
         CommandInfo {
@@ -58,25 +102,121 @@ pub fn all_commands() -> Vec<CommandInfo> {
         CommandInfo {
             name: "cd",
             description: "Change directory",
-            usage: "cd <path>",
+            usage: "cd [path | -]",
+            hidden: false,
+        },
+        CommandInfo {
+            name: "pushd",
+            description: "Save the current directory and change to another",
+            usage: "pushd [dir]",
+            hidden: false,
+        },
+        CommandInfo {
+            name: "popd",
+            description: "Return to the last directory saved by pushd",
+            usage: "popd",
+            hidden: false,
+        },
+        CommandInfo {
+            name: "dirs",
+            description: "Show the directory stack",
+            usage: "dirs [-v] [-c]",
             hidden: false,
         },
         CommandInfo {
             name: "ls",
             description: "List directory contents",
-            usage: "ls [path]",
+            usage: "ls [-a] [path]",
             hidden: false,
         },
         CommandInfo {
             name: "cat",
             description: "Display file contents",
-            usage: "cat <file>",
+            usage: "cat [--raw] <file>",
+            hidden: false,
+        },
+        CommandInfo {
+            name: "grep",
+            description: "Search files for a pattern",
+            usage: "grep [-i] [-n] [--] <pattern> <file>...",
+            hidden: false,
+        },
+        CommandInfo {
+            name: "view",
+            description: "Show an image as text art",
+            usage: "view [--ascii|--inline] [--width N] <image>",
+            hidden: false,
+        },
+        CommandInfo {
+            name: "less",
+            description: "Page through a file",
+            usage: "less <file>",
             hidden: false,
         },
         CommandInfo {
             name: "tree",
             description: "Show directory tree",
-            usage: "tree <path>",
+            usage: "tree [-a] <path>",
+            hidden: false,
+        },
+        CommandInfo {
+            name: "diff",
+            description: "Compare two files, or a file with its original",
+            usage: "diff [-u] <file> [other]",
+            hidden: false,
+        },
+        CommandInfo {
+            name: "open",
+            description: "Open a URL, or the nth link from the last output",
+            usage: "open [url | n]",
+            hidden: false,
+        },
+        CommandInfo {
+            name: "download",
+            description: "Download a file",
+            usage: "download <file>",
+            hidden: false,
+        },
+        CommandInfo {
+            name: "tar",
+            description: "Download a directory as a tarball",
+            usage: "tar -czf <archive.tar.gz> <path>",
+            hidden: false,
+        },
+        CommandInfo {
+            name: "zip",
+            description: "Download a directory as a zip",
+            usage: "zip -r <archive.zip> <path>",
+            hidden: false,
+        },
+        CommandInfo {
+            name: "touch",
+            description: "Create an empty file",
+            usage: "touch <file>",
+            hidden: false,
+        },
+        CommandInfo {
+            name: "mkdir",
+            description: "Create a directory",
+            usage: "mkdir <dir>",
+            hidden: false,
+        },
+        CommandInfo {
+            name: "rm",
+            description: "Remove files",
+            usage: "rm [-rf] <path>",
+            hidden: false,
+        },
+        CommandInfo {
+            name: "reset-fs",
+            description: "Discard your filesystem changes",
+            usage: "reset-fs",
+            hidden: false,
+        },
+        CommandInfo {
+            name: "nano",
+            description: "Edit a file",
+            usage: "nano <file>",
             hidden: false,
         },
         CommandInfo {
@@ -85,6 +225,12 @@ pub fn all_commands() -> Vec<CommandInfo> {
             usage: "theme [claude|green|amber|crt|matrix]",
             hidden: false,
         },
+        CommandInfo {
+            name: "lang",
+            description: "Change the language",
+            usage: "lang [code]",
+            hidden: false,
+        },
         CommandInfo {
             name: "history",
             description: "Show command history",
@@ -116,12 +262,6 @@ pub fn all_commands() -> Vec<CommandInfo> {
             usage: "sudo <command>",
             hidden: true,
         },
-        CommandInfo {
-            name: "rm",
-            description: "Remove files",
-            usage: "rm <file>",
-            hidden: true,
-        },
         CommandInfo {
             name: "neofetch",
             description: "System info",
@@ -134,6 +274,18 @@ pub fn all_commands() -> Vec<CommandInfo> {
             usage: "cowsay [message]",
             hidden: true,
         },
+        CommandInfo {
+            name: "vi",
+            description: "Edit a file (it's nano in disguise)",
+            usage: "vi <file>",
+            hidden: true,
+        },
+        CommandInfo {
+            name: "more",
+            description: "Page through a file",
+            usage: "more <file>",
+            hidden: true,
+        },
         CommandInfo {
             name: "exit",
             description: "Exit terminal",
@@ -141,16 +293,18 @@ pub fn all_commands() -> Vec<CommandInfo> {
             hidden: true,
         },
         CommandInfo {
-            name: "ttt",
-            description: "Play Tic-Tac-Toe",
-            usage: "ttt",
-            hidden: false,
-        },
-        CommandInfo {
-            name: "typing",
-            description: "Typing speed test",
-            usage: "typing",
+            name: "games",
+            description: "List the games you can play",
+            usage: "games",
             hidden: false,
         },
-    ]
+    ];
+    // Each game is its own command
+    commands.extend(games::all().iter().map(|game| CommandInfo {
+        name: game.name(),
+        description: game.description(),
+        usage: game.name(),
+        hidden: false,
+    }));
+    commands
 }
@@ -0,0 +1,376 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::commands::pager::Pager;
+use crate::commands::registry::all_commands;
+use crate::commands::{filesystem, portfolio, CommandOutput, FullScreen, LineStyle};
+use crate::filesystem::model::{display_path, VfsNode, VirtualFs};
+use crate::filesystem::overlay::Overlay;
+use crate::i18n;
+use crate::state::shell::Shell;
+use crate::state::viewport;
+
+const USAGE: &str = "search <query> | search --open <n>";
+
+/// Results shown per search, and so the most `--open` can reach.
+const MAX_RESULTS: usize = 10;
+
+/// Something that can turn up in a search, as the visitor would see it.
+#[derive(Clone, Debug)]
+struct Document {
+    /// Where it lives: a path, a command, or a help page
+    title: String,
+    kind: &'static str,
+    lines: Vec<(String, LineStyle)>,
+}
+
+impl Document {
+    /// What to run to see the whole document.
+    fn command(&self) -> String {
+        match self.kind {
+            "file" => format!("less {}", self.title),
+            "help" => format!("cat /usr/bin/{}", self.title),
+            _ => self.title.clone(),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+struct Hit {
+    doc: usize,
+    /// The line that best matches the query
+    line: usize,
+    /// The query term found on that line, to highlight in the pager
+    term: String,
+}
+
+/// An inverted index over files, command output and help pages, plus the
+/// results of the last search for `search --open <n>`.
+#[derive(Clone, Debug, Default)]
+pub struct Search {
+    docs: Vec<Document>,
+    /// Each word to every (document, line) it appears on, once per use
+    postings: HashMap<String, Vec<(usize, usize)>>,
+    /// The filesystem changes and language the index was built from
+    built_for: Option<(Overlay, &'static str)>,
+    hits: Vec<Hit>,
+}
+
+impl Search {
+    /// Index everything again if the visitor changed a file or the
+    /// language since the last build.
+    pub fn refresh(&mut self, fs: &VirtualFs) {
+        let stamp = (fs.overlay.clone(), i18n::current());
+        if self.built_for.as_ref() == Some(&stamp) {
+            return;
+        }
+
+        self.docs = documents(fs);
+        self.postings.clear();
+        for (doc, document) in self.docs.iter().enumerate() {
+            for (line, (text, _)) in document.lines.iter().enumerate() {
+                for (_, word) in words(text) {
+                    self.postings
+                        .entry(word.to_lowercase())
+                        .or_default()
+                        .push((doc, line));
+                }
+            }
+        }
+        self.built_for = Some(stamp);
+        self.hits.clear();
+    }
+
+    /// Commands that would show the best matches for any of `terms`.
+    pub fn suggest(&mut self, fs: &VirtualFs, terms: &[String], limit: usize) -> Vec<String> {
+        self.refresh(fs);
+        self.rank(terms, false)
+            .into_iter()
+            .take(limit)
+            .map(|hit| self.docs[hit.doc].command())
+            .collect()
+    }
+
+    /// Rank documents containing every term, or any of them unless
+    /// `require_all`. Whole words count for more than prefixes, rare words
+    /// for more than common ones, and a match in the title gives a boost.
+    fn rank(&self, terms: &[String], require_all: bool) -> Vec<Hit> {
+        let total = self.docs.len() as f64;
+        let mut scores: HashMap<usize, f64> = HashMap::new();
+        // Per document, the lines each term was found on
+        let mut found: HashMap<usize, Vec<HashSet<usize>>> = HashMap::new();
+
+        for (t, term) in terms.iter().enumerate() {
+            let mut counts: HashMap<usize, f64> = HashMap::new();
+            for (word, postings) in &self.postings {
+                if !word.starts_with(term.as_str()) {
+                    continue;
+                }
+                let weight = if word == term { 1.0 } else { 0.5 };
+                for &(doc, line) in postings {
+                    *counts.entry(doc).or_default() += weight;
+                    found
+                        .entry(doc)
+                        .or_insert_with(|| vec![HashSet::new(); terms.len()])[t]
+                        .insert(line);
+                }
+            }
+
+            let idf = (1.0 + total / counts.len().max(1) as f64).ln();
+            for (doc, count) in counts {
+                let mut score = (1.0 + count.ln().max(0.0)) * idf;
+                if self.docs[doc].title.to_lowercase().contains(term.as_str()) {
+                    score += idf;
+                }
+                *scores.entry(doc).or_default() += score;
+            }
+        }
+
+        let mut ranked: Vec<(usize, f64)> = scores
+            .into_iter()
+            .filter(|(doc, _)| !require_all || found[doc].iter().all(|lines| !lines.is_empty()))
+            .collect();
+        ranked.sort_by(|a, b| {
+            b.1.total_cmp(&a.1)
+                .then_with(|| self.docs[a.0].title.cmp(&self.docs[b.0].title))
+        });
+
+        ranked
+            .into_iter()
+            .map(|(doc, _)| {
+                // The earliest line with the most distinct terms on it
+                let lines = &found[&doc];
+                let line = lines
+                    .iter()
+                    .flatten()
+                    .copied()
+                    .max_by_key(|line| {
+                        let terms_on_line = lines.iter().filter(|set| set.contains(line)).count();
+                        (terms_on_line, std::cmp::Reverse(*line))
+                    })
+                    .unwrap_or(0);
+                let term = terms
+                    .iter()
+                    .zip(lines)
+                    .find(|(_, set)| set.contains(&line))
+                    .map(|(term, _)| term.clone())
+                    .unwrap_or_default();
+                Hit { doc, line, term }
+            })
+            .collect()
+    }
+}
+
+/// Everything worth finding: readable files, the portfolio commands'
+/// output, and a help page per command.
+fn documents(fs: &VirtualFs) -> Vec<Document> {
+    let mut paths: Vec<&String> = fs
+        .nodes
+        .iter()
+        // Generated files change on every read, so they can't be indexed
+        .filter(|(_, node)| matches!(node, VfsNode::File { .. }))
+        .map(|(path, _)| path)
+        .collect();
+    paths.sort();
+
+    let mut docs: Vec<Document> = paths
+        .into_iter()
+        .map(|path| Document {
+            title: display_path(path),
+            kind: "file",
+            lines: filesystem::cat(fs, "/", std::slice::from_ref(path)).lines,
+        })
+        .collect();
+
+    let commands = [
+        ("about", portfolio::about()),
+        ("skills", portfolio::skills(&[])),
+        ("projects", portfolio::projects(fs, &[])),
+        ("experience", portfolio::experience(&[])),
+    ];
+    docs.extend(commands.into_iter().map(|(name, output)| Document {
+        title: name.to_string(),
+        kind: "command",
+        lines: output.lines,
+    }));
+
+    docs.extend(
+        all_commands()
+            .into_iter()
+            .filter(|info| !info.hidden)
+            .map(|info| Document {
+                title: info.name.to_string(),
+                kind: "help",
+                lines: vec![
+                    (info.usage.to_string(), LineStyle::Accent),
+                    (super::description(&info), LineStyle::Normal),
+                ],
+            }),
+    );
+    docs
+}
+
+pub fn search(fs: &VirtualFs, shell: &mut Shell, args: &[String]) -> CommandOutput {
+    if args.first().is_some_and(|a| a == "--open") {
+        return match args.get(1).and_then(|n| n.parse::<usize>().ok()) {
+            Some(n) => open(&shell.search, n),
+            None => usage(),
+        };
+    }
+
+    let query = args.join(" ");
+    let terms: Vec<String> = words(&query)
+        .into_iter()
+        .fold(Vec::new(), |mut terms, (_, word)| {
+            let word = word.to_lowercase();
+            if !terms.contains(&word) {
+                terms.push(word);
+            }
+            terms
+        });
+    if terms.is_empty() {
+        return usage();
+    }
+
+    let index = &mut shell.search;
+    index.refresh(fs);
+    let mut hits = index.rank(&terms, true);
+    if hits.is_empty() {
+        index.hits.clear();
+        return error(format!(
+            "search: {}",
+            i18n::tr("search.no_match", &[("query", &query)])
+        ));
+    }
+    let total = hits.len();
+    hits.truncate(MAX_RESULTS);
+
+    let width = viewport::columns().clamp(40, 100) - 5;
+    let mut lines = vec![(String::new(), LineStyle::Normal)];
+    for (i, hit) in hits.iter().enumerate() {
+        let doc = &index.docs[hit.doc];
+        lines.push((
+            format!(
+                "  {}. {}  ({})",
+                i + 1,
+                doc.title,
+                i18n::t(&format!("search.kind.{}", doc.kind))
+            ),
+            LineStyle::Accent,
+        ));
+        let (text, marks) = snippet(&doc.lines[hit.line].0, &terms, width);
+        let marks = marks.into_iter().map(|(s, e)| (s + 5, e + 5)).collect();
+        lines.push((format!("     {}", text), LineStyle::Highlight(marks)));
+        lines.push((String::new(), LineStyle::Normal));
+    }
+
+    let shown = if total > hits.len() {
+        i18n::tr(
+            "search.showing",
+            &[
+                ("shown", &hits.len().to_string()),
+                ("total", &total.to_string()),
+            ],
+        )
+    } else if total == 1 {
+        i18n::t("search.result_count_one")
+    } else {
+        i18n::tr("search.result_count", &[("count", &total.to_string())])
+    };
+    lines.push((
+        i18n::tr("search.hint", &[("shown", &shown)]),
+        LineStyle::Muted,
+    ));
+    lines.push((String::new(), LineStyle::Normal));
+    index.hits = hits;
+    CommandOutput::new(lines, false, true)
+}
+
+/// Page through the document behind a result, starting at the match.
+fn open(index: &Search, n: usize) -> CommandOutput {
+    if index.hits.is_empty() {
+        return error(format!("search: {}", i18n::t("search.nothing_to_open")));
+    }
+    let Some(hit) = n.checked_sub(1).and_then(|i| index.hits.get(i)) else {
+        return error(format!(
+            "search: {}",
+            i18n::tr(
+                "search.no_result",
+                &[
+                    ("n", &n.to_string()),
+                    ("count", &index.hits.len().to_string())
+                ],
+            )
+        ));
+    };
+
+    let lines = index.docs[hit.doc].lines.clone();
+    CommandOutput::new(vec![], false, false).with_full_screen(FullScreen::Pager(
+        Pager::new(lines).at_match(&hit.term, hit.line),
+    ))
+}
+
+/// Up to `width` characters of `line` around its first match, with the
+/// byte ranges of every matching word in the returned text.
+fn snippet(line: &str, terms: &[String], width: usize) -> (String, Vec<(usize, usize)>) {
+    let line = line.trim();
+    let matches = |text: &str| -> Vec<(usize, usize)> {
+        words(text)
+            .into_iter()
+            .filter(|(_, word)| {
+                let word = word.to_lowercase();
+                terms.iter().any(|term| word.starts_with(term.as_str()))
+            })
+            .map(|(start, word)| (start, start + word.len()))
+            .collect()
+    };
+
+    let chars: Vec<(usize, char)> = line.char_indices().collect();
+    if chars.len() <= width {
+        return (line.to_string(), matches(line));
+    }
+
+    // Start a little before the first match so it has some context
+    let first = matches(line).first().map_or(0, |(start, _)| *start);
+    let first_char = chars.iter().position(|(i, _)| *i >= first).unwrap_or(0);
+    let start = first_char
+        .saturating_sub(width / 4)
+        .min(chars.len() - width + 1);
+    let end = (start + width - 1).min(chars.len());
+
+    let byte = |i: usize| chars.get(i).map_or(line.len(), |(b, _)| *b);
+    let mut text = String::new();
+    if start > 0 {
+        text.push('…');
+    }
+    text.push_str(&line[byte(start)..byte(end)]);
+    if end < chars.len() {
+        text.push('…');
+    }
+    let marks = matches(&text);
+    (text, marks)
+}
+
+/// The words in `text` with their byte offsets.
+pub fn words(text: &str) -> Vec<(usize, &str)> {
+    let mut words = Vec::new();
+    let mut start = None;
+    for (i, c) in text.char_indices() {
+        if c.is_alphanumeric() {
+            start.get_or_insert(i);
+        } else if let Some(s) = start.take() {
+            words.push((s, &text[s..i]));
+        }
+    }
+    if let Some(s) = start {
+        words.push((s, &text[s..]));
+    }
+    words
+}
+
+fn usage() -> CommandOutput {
+    error(i18n::tr("shell.usage", &[("usage", USAGE)]))
+}
+
+fn error(message: String) -> CommandOutput {
+    CommandOutput::new(vec![(message, LineStyle::Error)], false, false)
+}
@@ -1,6 +1,7 @@
 use leptos::prelude::{Get, Set};
 
 use crate::commands::{CommandOutput, LineStyle};
+use crate::i18n;
 use crate::state::theme::{ColorScheme, ThemeState};
 
 pub fn theme_command(args: &[String], theme: &ThemeState) -> CommandOutput {
@@ -13,36 +14,29 @@ pub fn theme_command(args: &[String], theme: &ThemeState) -> CommandOutput {
 }
 
 fn theme_help() -> CommandOutput {
-    CommandOutput {
-        lines: vec![
-            (String::new(), LineStyle::Normal),
-            ("  Theme commands:".to_string(), LineStyle::Accent),
-            (String::new(), LineStyle::Normal),
-            (
-                "  theme claude       Switch to Claude orange theme".to_string(),
-                LineStyle::Normal,
-            ),
-            (
-                "  theme green        Switch to classic green terminal".to_string(),
-                LineStyle::Normal,
-            ),
-            (
-                "  theme amber        Switch to retro amber terminal".to_string(),
-                LineStyle::Normal,
-            ),
-            (
-                "  theme crt          Toggle CRT scanline effect".to_string(),
-                LineStyle::Normal,
-            ),
-            (
-                "  theme matrix       Toggle matrix rain effect".to_string(),
-                LineStyle::Normal,
+    let mut lines = vec![
+        (String::new(), LineStyle::Normal),
+        (format!("  {}", i18n::t("theme.title")), LineStyle::Accent),
+        (String::new(), LineStyle::Normal),
+    ];
+    for name in ["claude", "green", "amber", "crt", "matrix"] {
+        lines.push((
+            format!(
+                "  theme {:<13}{}",
+                name,
+                i18n::t(&format!("theme.help.{}", name))
             ),
-            (String::new(), LineStyle::Normal),
-        ],
+            LineStyle::Normal,
+        ));
+    }
+    lines.push((String::new(), LineStyle::Normal));
+    CommandOutput {
+        lines,
         clear_screen: false,
         typewriter: true,
         start_game: None,
+        full_screen: None,
+        prompt: None,
     }
 }
 
@@ -52,25 +46,38 @@ fn set_color_scheme(name: &str, theme: &ThemeState) -> CommandOutput {
             theme.color_scheme.set(scheme);
             CommandOutput {
                 lines: vec![(
-                    format!("  Switched to {} theme.", scheme.name()),
+                    format!(
+                        "  {}",
+                        i18n::tr("theme.switched", &[("name", scheme.name())])
+                    ),
                     LineStyle::Accent,
                 )],
                 clear_screen: false,
                 typewriter: false,
                 start_game: None,
+                full_screen: None,
+                prompt: None,
             }
         }
         None => CommandOutput {
             lines: vec![
-                (format!("  Unknown theme: {}", name), LineStyle::Error),
                 (
-                    "  Available: claude, green, amber".to_string(),
+                    format!("  {}", i18n::tr("theme.unknown", &[("name", name)])),
+                    LineStyle::Error,
+                ),
+                (
+                    format!(
+                        "  {}",
+                        i18n::tr("theme.available", &[("names", "claude, green, amber")])
+                    ),
                     LineStyle::Muted,
                 ),
             ],
             clear_screen: false,
             typewriter: false,
             start_game: None,
+            full_screen: None,
+            prompt: None,
         },
     }
 }
@@ -83,15 +90,17 @@ fn toggle_crt(args: &[String], theme: &ThemeState) -> CommandOutput {
     };
     theme.crt_enabled.set(new_state);
     let msg = if new_state {
-        "  CRT effect enabled."
+        i18n::t("theme.crt_on")
     } else {
-        "  CRT effect disabled."
+        i18n::t("theme.crt_off")
     };
     CommandOutput {
-        lines: vec![(msg.to_string(), LineStyle::Accent)],
+        lines: vec![(format!("  {}", msg), LineStyle::Accent)],
         clear_screen: false,
         typewriter: false,
         start_game: None,
+        full_screen: None,
+        prompt: None,
     }
 }
 
@@ -104,14 +113,16 @@ fn toggle_matrix(args: &[String], theme: &ThemeState) -> CommandOutput {
     theme.matrix_rain.set(new_state);
 
     let msg = if new_state {
-        "  Matrix rain enabled."
+        i18n::t("theme.matrix_on")
     } else {
-        "  Matrix rain disabled."
+        i18n::t("theme.matrix_off")
     };
     CommandOutput {
-        lines: vec![(msg.to_string(), LineStyle::Accent)],
+        lines: vec![(format!("  {}", msg), LineStyle::Accent)],
         clear_screen: false,
         typewriter: false,
         start_game: None,
+        full_screen: None,
+        prompt: None,
     }
 }
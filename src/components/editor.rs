@@ -0,0 +1,58 @@
+use crate::commands::editor::Editor;
+use leptos::prelude::*;
+
+#[component]
+pub fn EditorView(editor: Editor) -> impl IntoView {
+    let gutter_width = editor.lines.len().to_string().len().max(3);
+    let end = (editor.scroll + editor.height).min(editor.lines.len());
+
+    let rows = (editor.scroll..end)
+        .map(|row| {
+            let line = &editor.lines[row];
+            let gutter = format!("{:>width$} ", row + 1, width = gutter_width);
+
+            let text = if row == editor.row {
+                let chars: Vec<char> = line.chars().collect();
+                let col = editor.col.min(chars.len());
+                let before: String = chars[..col].iter().collect();
+                let cursor: String = chars.get(col).map(|c| c.to_string()).unwrap_or(" ".into());
+                let after: String = chars.get(col + 1..).unwrap_or(&[]).iter().collect();
+                view! {
+                    <span>{before}</span>
+                    <span class="editor-cursor">{cursor}</span>
+                    <span>{after}</span>
+                }
+                .into_any()
+            } else {
+                view! { <span>{line.clone()}</span> }.into_any()
+            };
+
+            view! {
+                <div class="editor-line">
+                    <span class="editor-gutter">{gutter}</span>
+                    {text}
+                </div>
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let modified = if editor.dirty { "Modified" } else { "" };
+
+    view! {
+        <div class="editor">
+            <div class="editor-header">
+                <span>"  nano"</span>
+                <span>{format!("File: {}", editor.path)}</span>
+                <span>{modified}</span>
+            </div>
+            <div class="editor-body">{rows}</div>
+            <div class="editor-status">{editor.status.clone()}</div>
+            <div class="editor-help">
+                <span class="editor-key">"^S"</span>" Save   "
+                <span class="editor-key">"^X"</span>" Exit   "
+                <span class="editor-key">"Arrows"</span>" Move   "
+                <span class="editor-key">"PgUp/PgDn"</span>" Scroll"
+            </div>
+        </div>
+    }
+}
@@ -1,4 +1,6 @@
+pub mod editor;
 pub mod matrix_rain;
 pub mod mobile_toolbar;
+pub mod pager;
 pub mod typewriter;
 pub mod welcome;
@@ -0,0 +1,59 @@
+use crate::commands::pager::Pager;
+use crate::commands::LineStyle;
+use leptos::prelude::*;
+
+#[component]
+pub fn PagerView(pager: Pager) -> impl IntoView {
+    let end = (pager.top + pager.height).min(pager.lines.len());
+    let needle = pager.query.to_lowercase();
+
+    let rows = pager.lines[pager.top..end]
+        .iter()
+        .map(|(text, style)| {
+            let content = highlight(text, &needle);
+            match style {
+                LineStyle::Link(href) => view! {
+                    <div class=style.css_class()>
+                        <a href=href.clone() target="_blank" rel="noopener noreferrer">{content}</a>
+                    </div>
+                }
+                .into_any(),
+                LineStyle::Image(src) => view! {
+                    <div class=style.css_class()>
+                        <img src=src.clone() alt=text.clone()/>
+                    </div>
+                }
+                .into_any(),
+                _ => view! { <div class=style.css_class()>{content}</div> }.into_any(),
+            }
+        })
+        .collect::<Vec<_>>();
+
+    view! {
+        <div class="pager">
+            <div class="pager-body">{rows}</div>
+            <div class="pager-status">{pager.status()}</div>
+        </div>
+    }
+}
+
+/// Split a line around case-insensitive matches of the search query.
+fn highlight(text: &str, needle: &str) -> Vec<AnyView> {
+    let lower = text.to_lowercase();
+    if needle.is_empty() || lower.len() != text.len() {
+        return vec![view! { <span>{text.to_string()}</span> }.into_any()];
+    }
+
+    let mut parts = Vec::new();
+    let mut last = 0;
+    for (start, _) in lower.match_indices(needle) {
+        parts.push(view! { <span>{text[last..start].to_string()}</span> }.into_any());
+        let end = start + needle.len();
+        parts.push(
+            view! { <mark class="pager-match">{text[start..end].to_string()}</mark> }.into_any(),
+        );
+        last = end;
+    }
+    parts.push(view! { <span>{text[last..].to_string()}</span> }.into_any());
+    parts
+}
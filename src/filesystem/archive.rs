@@ -0,0 +1,184 @@
+use flate2::write::{DeflateEncoder, GzEncoder};
+use flate2::{Compression, Crc};
+use std::borrow::Cow;
+use std::io::Write;
+
+/// One archive member: a path relative to the archive root, and the file
+/// bytes (`None` for directories).
+pub type Entry<'a> = (String, Option<Cow<'a, [u8]>>);
+
+/// Pack entries into a POSIX ustar archive, optionally gzipped.
+pub fn tar(entries: &[Entry], mtime_secs: u64, gzip: bool) -> Vec<u8> {
+    let mut out = Vec::new();
+
+    for (path, data) in entries {
+        let (name, size, mode, kind) = match data {
+            Some(bytes) => (path.clone(), bytes.len(), 0o644, b'0'),
+            None => (format!("{}/", path), 0, 0o755, b'5'),
+        };
+
+        let (prefix, short) = match ustar_split(&name) {
+            Some(split) => split,
+            None => {
+                // Too long for ustar: a GNU long-name entry carries the full
+                // path, and the header after it keeps a truncated copy
+                let mut long = name.as_bytes().to_vec();
+                long.push(0);
+                out.extend_from_slice(&tar_header(
+                    "",
+                    "././@LongLink",
+                    long.len(),
+                    0o644,
+                    b'L',
+                    mtime_secs,
+                ));
+                append_padded(&mut out, &long);
+                ("", truncate(&name, 100))
+            }
+        };
+        out.extend_from_slice(&tar_header(prefix, short, size, mode, kind, mtime_secs));
+        if let Some(bytes) = data {
+            append_padded(&mut out, bytes);
+        }
+    }
+
+    // Two empty blocks mark the end of the archive
+    out.resize(out.len() + 1024, 0);
+
+    if gzip {
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::best());
+        let _ = encoder.write_all(&out);
+        encoder.finish().unwrap_or_default()
+    } else {
+        out
+    }
+}
+
+/// Split a path across the ustar prefix (155 bytes) and name (100 bytes)
+/// fields at a '/', or `None` if no split fits.
+fn ustar_split(path: &str) -> Option<(&str, &str)> {
+    if path.len() <= 100 {
+        return Some(("", path));
+    }
+    path.rmatch_indices('/')
+        .map(|(i, _)| i)
+        .find(|&i| i <= 155 && i + 1 < path.len() && path.len() - i - 1 <= 100)
+        .map(|i| (&path[..i], &path[i + 1..]))
+}
+
+/// The longest prefix of `text` that fits in `max` bytes without splitting
+/// a character.
+fn truncate(text: &str, max: usize) -> &str {
+    let mut end = max.min(text.len());
+    while !text.is_char_boundary(end) {
+        end -= 1;
+    }
+    &text[..end]
+}
+
+fn append_padded(out: &mut Vec<u8>, bytes: &[u8]) {
+    out.extend_from_slice(bytes);
+    out.resize(out.len().next_multiple_of(512), 0);
+}
+
+fn tar_header(prefix: &str, name: &str, size: usize, mode: u32, kind: u8, mtime: u64) -> [u8; 512] {
+    let mut header = [0u8; 512];
+
+    put(&mut header[0..100], name.as_bytes());
+    put(&mut header[100..108], format!("{:07o}\0", mode).as_bytes());
+    put(&mut header[108..116], b"0000000\0");
+    put(&mut header[116..124], b"0000000\0");
+    put(&mut header[124..136], format!("{:011o}\0", size).as_bytes());
+    put(
+        &mut header[136..148],
+        format!("{:011o}\0", mtime).as_bytes(),
+    );
+    header[156] = kind;
+    put(&mut header[257..265], b"ustar\x0000");
+    put(&mut header[265..297], b"visitor");
+    put(&mut header[297..329], b"visitor");
+    put(&mut header[345..500], prefix.as_bytes());
+
+    // Checksum is computed with its own field filled with spaces
+    header[148..156].fill(b' ');
+    let sum: u32 = header.iter().map(|&b| b as u32).sum();
+    put(&mut header[148..156], format!("{:06o}\0 ", sum).as_bytes());
+
+    header
+}
+
+fn put(field: &mut [u8], value: &[u8]) {
+    let len = value.len().min(field.len());
+    field[..len].copy_from_slice(&value[..len]);
+}
+
+/// Pack entries into a deflate-compressed zip archive.
+pub fn zip(entries: &[Entry], dos_time: u16, dos_date: u16) -> Vec<u8> {
+    let mut out = Vec::new();
+    let mut central = Vec::new();
+    let mut count: u16 = 0;
+
+    for (path, data) in entries {
+        let (name, bytes, external_attrs) = match data {
+            Some(bytes) => (path.clone(), bytes.as_ref(), 0o100644u32 << 16),
+            None => (format!("{}/", path), &[][..], (0o040755u32 << 16) | 0x10),
+        };
+
+        let mut crc = Crc::new();
+        crc.update(bytes);
+
+        let mut encoder = DeflateEncoder::new(Vec::new(), Compression::best());
+        let _ = encoder.write_all(bytes);
+        let compressed = encoder.finish().unwrap_or_default();
+
+        let offset = out.len() as u32;
+
+        // Local file header
+        out.extend_from_slice(&0x04034b50u32.to_le_bytes());
+        out.extend_from_slice(&20u16.to_le_bytes()); // version needed
+        out.extend_from_slice(&0x0800u16.to_le_bytes()); // UTF-8 names
+        out.extend_from_slice(&8u16.to_le_bytes()); // deflate
+        out.extend_from_slice(&dos_time.to_le_bytes());
+        out.extend_from_slice(&dos_date.to_le_bytes());
+        out.extend_from_slice(&crc.sum().to_le_bytes());
+        out.extend_from_slice(&(compressed.len() as u32).to_le_bytes());
+        out.extend_from_slice(&(bytes.len() as u32).to_le_bytes());
+        out.extend_from_slice(&(name.len() as u16).to_le_bytes());
+        out.extend_from_slice(&0u16.to_le_bytes()); // extra field length
+        out.extend_from_slice(name.as_bytes());
+        out.extend_from_slice(&compressed);
+
+        // Matching central directory record
+        central.extend_from_slice(&0x02014b50u32.to_le_bytes());
+        central.extend_from_slice(&0x031eu16.to_le_bytes()); // made by: unix, v3.0
+        central.extend_from_slice(&20u16.to_le_bytes());
+        central.extend_from_slice(&0x0800u16.to_le_bytes());
+        central.extend_from_slice(&8u16.to_le_bytes());
+        central.extend_from_slice(&dos_time.to_le_bytes());
+        central.extend_from_slice(&dos_date.to_le_bytes());
+        central.extend_from_slice(&crc.sum().to_le_bytes());
+        central.extend_from_slice(&(compressed.len() as u32).to_le_bytes());
+        central.extend_from_slice(&(bytes.len() as u32).to_le_bytes());
+        central.extend_from_slice(&(name.len() as u16).to_le_bytes());
+        central.extend_from_slice(&[0; 8]); // extra, comment, disk, internal attrs
+        central.extend_from_slice(&external_attrs.to_le_bytes());
+        central.extend_from_slice(&offset.to_le_bytes());
+        central.extend_from_slice(name.as_bytes());
+
+        count += 1;
+    }
+
+    let central_offset = out.len() as u32;
+    out.extend_from_slice(&central);
+
+    // End of central directory
+    out.extend_from_slice(&0x06054b50u32.to_le_bytes());
+    out.extend_from_slice(&[0; 4]); // disk numbers
+    out.extend_from_slice(&count.to_le_bytes());
+    out.extend_from_slice(&count.to_le_bytes());
+    out.extend_from_slice(&(central.len() as u32).to_le_bytes());
+    out.extend_from_slice(&central_offset.to_le_bytes());
+    out.extend_from_slice(&0u16.to_le_bytes()); // comment length
+
+    out
+}
@@ -1,46 +1,124 @@
 use super::content;
 use super::model::VirtualFs;
+use super::procfs::Generator;
+use crate::commands::registry::all_commands;
+use crate::i18n;
+use crate::portfolio::{blog, portfolio, resume, text};
 
 pub fn build_default_fs() -> VirtualFs {
     let mut fs = VirtualFs::new();
+    let data = portfolio();
 
-    // Root directory
+    // Home directory, which `~` expands to
     fs.add_dir(
-        "~",
+        "/home/visitor",
         &[
             "about.txt",
             "contact.txt",
+            "experience.txt",
             "resume.txt",
+            "blog",
             "skills",
             "projects",
+            "photos",
+            ".bashrc",
+            ".secrets",
+            ".config",
         ],
     );
 
     // Root files
-    fs.add_file("~/about.txt", content::ABOUT_TEXT);
-    fs.add_file("~/contact.txt", content::CONTACT_TEXT);
-    fs.add_file("~/resume.txt", content::RESUME_TEXT);
+    fs.add_file("/home/visitor/about.txt", &text::about(data));
+    fs.add_file("/home/visitor/contact.txt", &text::contact(data));
+    fs.add_file("/home/visitor/experience.txt", &text::experience(data));
+    fs.add_file("/home/visitor/resume.txt", &resume::plain_text(data));
+
+    // Photos directory
+    fs.add_dir("/home/visitor/photos", &["me.png"]);
+    fs.add_binary("/home/visitor/photos/me.png", content::PHOTO_ME);
+
+    // Dotfiles, only visible with `ls -a`
+    fs.add_file("/home/visitor/.bashrc", content::BASHRC);
+    fs.add_file("/home/visitor/.secrets", &i18n::t("file.secrets"));
+    fs.add_dir("/home/visitor/.config", &["terminal.conf"]);
+    fs.add_file(
+        "/home/visitor/.config/terminal.conf",
+        content::TERMINAL_CONF,
+    );
 
     // Skills directory
     fs.add_dir(
-        "~/skills",
+        "/home/visitor/skills",
         &["languages.txt", "frameworks.txt", "tools.txt"],
     );
-    fs.add_file("~/skills/languages.txt", content::SKILLS_LANGUAGES);
-    fs.add_file("~/skills/frameworks.txt", content::SKILLS_FRAMEWORKS);
-    fs.add_file("~/skills/tools.txt", content::SKILLS_TOOLS);
+    fs.add_file("/home/visitor/skills/languages.txt", &text::languages(data));
+    fs.add_file(
+        "/home/visitor/skills/frameworks.txt",
+        &text::frameworks(data),
+    );
+    fs.add_file("/home/visitor/skills/tools.txt", &text::tools(data));
+
+    // Projects directory, one folder per project
+    let slugs: Vec<&str> = data.projects.iter().map(|p| p.slug.as_str()).collect();
+    fs.add_dir("/home/visitor/projects", &slugs);
+    for project in &data.projects {
+        let dir = format!("/home/visitor/projects/{}", project.slug);
+        let screenshot = project
+            .screenshot
+            .as_deref()
+            .and_then(|name| content::screenshot(name).map(|bytes| (name, bytes)));
+        match screenshot {
+            Some((name, bytes)) => {
+                fs.add_dir(&dir, &["README.md", name]);
+                fs.add_binary(&format!("{}/{}", dir, name), bytes);
+            }
+            None => fs.add_dir(&dir, &["README.md"]),
+        }
+        fs.add_file(&format!("{}/README.md", dir), &text::readme(project));
+    }
 
-    // Projects directory
-    fs.add_dir("~/projects", &["project1", "project2", "project3"]);
+    // Blog posts, as markdown with their frontmatter
+    let posts: Vec<String> = blog::SOURCES
+        .iter()
+        .map(|(slug, _)| format!("{}.md", slug))
+        .collect();
+    let names: Vec<&str> = posts.iter().map(String::as_str).collect();
+    fs.add_dir("/home/visitor/blog", &names);
+    for (slug, source) in blog::SOURCES {
+        fs.add_file(&format!("/home/visitor/blog/{}.md", slug), source);
+    }
 
-    fs.add_dir("~/projects/project1", &["README.md"]);
-    fs.add_file("~/projects/project1/README.md", content::PROJECT1_README);
+    // Root directory
+    fs.add_dir("/", &["etc", "home", "proc", "tmp", "usr", "var"]);
+    fs.add_dir("/home", &["visitor"]);
+    fs.add_dir("/tmp", &[]);
+    fs.add_dir("/var", &["log"]);
+    fs.add_dir("/var/log", &[]);
 
-    fs.add_dir("~/projects/project2", &["README.md"]);
-    fs.add_file("~/projects/project2/README.md", content::PROJECT2_README);
+    // System files, generated from the live session on every read
+    fs.add_dir("/etc", &["hostname", "passwd", "theme"]);
+    fs.add_generated("/etc/hostname", Generator::Hostname);
+    fs.add_file("/etc/passwd", content::PASSWD);
+    fs.add_generated("/etc/theme", Generator::Theme);
+    fs.add_dir("/proc", &["cpuinfo", "history", "uptime"]);
+    fs.add_generated("/proc/cpuinfo", Generator::CpuInfo);
+    fs.add_generated("/proc/history", Generator::History);
+    fs.add_generated("/proc/uptime", Generator::Uptime);
 
-    fs.add_dir("~/projects/project3", &["README.md"]);
-    fs.add_file("~/projects/project3/README.md", content::PROJECT3_README);
+    // Every command the shell knows is an "executable" in /usr/bin
+    let commands = all_commands();
+    let names: Vec<&str> = commands.iter().map(|c| c.name).collect();
+    fs.add_dir("/usr", &["bin"]);
+    fs.add_dir("/usr/bin", &names);
+    for command in &commands {
+        fs.add_file(
+            &format!("/usr/bin/{}", command.name),
+            &format!(
+                "#!/bin/sh\n# {} - {}\n# usage: {}\nexec portfolio {} \"$@\"\n",
+                command.name, command.description, command.usage, command.name
+            ),
+        );
+    }
 
     fs
 }
@@ -1,3 +1,6 @@
+pub mod archive;
 pub mod builder;
 pub mod content;
 pub mod model;
+pub mod overlay;
+pub mod procfs;
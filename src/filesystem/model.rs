@@ -1,20 +1,75 @@
-use std::{collections::HashMap, fmt::format};
+use super::overlay::{Overlay, OverlayEntry};
+use super::procfs::{Generator, Session, SessionSource};
+use crate::i18n;
+use std::borrow::Cow;
+use std::collections::HashMap;
+
+/// The visitor's home directory, which `~` expands to.
+pub const HOME: &str = "/home/visitor";
+
+/// Scratch space outside home that the visitor may still write to.
+const TMP: &str = "/tmp";
 
 #[derive(Clone, Debug)]
 pub enum VfsNode {
-    File { name: String, content: String },
-    Directory { name: String, children: Vec<String> },
+    File {
+        name: String,
+        content: String,
+    },
+    /// Bundled binary asset such as an image; never edited in place
+    Binary {
+        name: String,
+        data: &'static [u8],
+    },
+    /// Read-only file whose content is computed on every read
+    Generated {
+        name: String,
+        generator: Generator,
+    },
+    Directory {
+        name: String,
+        children: Vec<String>,
+    },
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum FsError {
+    NotFound,
+    NotADirectory,
+    IsADirectory,
+    AlreadyExists,
+    DirectoryNotEmpty,
+    PermissionDenied,
+}
+
+impl FsError {
+    pub fn message(&self) -> String {
+        i18n::t(match self {
+            FsError::NotFound => "fs.not_found",
+            FsError::NotADirectory => "fs.not_a_directory",
+            FsError::IsADirectory => "fs.is_a_directory",
+            FsError::AlreadyExists => "fs.already_exists",
+            FsError::DirectoryNotEmpty => "fs.not_empty",
+            FsError::PermissionDenied => "fs.permission_denied",
+        })
+    }
 }
 
 #[derive(Clone, Debug)]
 pub struct VirtualFs {
     pub nodes: HashMap<String, VfsNode>,
+    pub overlay: Overlay,
+    /// Where generated files read the theme and history from, once the app
+    /// has connected it
+    pub session: Option<SessionSource>,
 }
 
 impl VirtualFs {
     pub fn new() -> Self {
         Self {
             nodes: HashMap::new(),
+            overlay: Overlay::default(),
+            session: None,
         }
     }
 
@@ -40,46 +95,219 @@ impl VirtualFs {
         );
     }
 
-    /// Resolve a path relative to cwd. Handles "~", "..", ".", and absolute paths.
-    pub fn resolve_path(&self, cwd: &str, input: &str) -> String {
-        let input = input.trim();
+    pub fn add_binary(&mut self, path: &str, data: &'static [u8]) {
+        let name = path.rsplit('/').next().unwrap_or(path).to_string();
+        self.nodes
+            .insert(path.to_string(), VfsNode::Binary { name, data });
+    }
+
+    pub fn add_generated(&mut self, path: &str, generator: Generator) {
+        let name = path.rsplit('/').next().unwrap_or(path).to_string();
+        self.nodes
+            .insert(path.to_string(), VfsNode::Generated { name, generator });
+    }
+
+    /// Write a file, creating it if needed. Recorded in the overlay.
+    pub fn write_file(&mut self, path: &str, content: &str) -> Result<(), FsError> {
+        if self.is_dir(path) {
+            return Err(FsError::IsADirectory);
+        }
+        if self.is_read_only(path) {
+            return Err(FsError::PermissionDenied);
+        }
+        self.attach_to_parent(path)?;
+        self.add_file(path, content);
+        self.overlay.record(
+            path,
+            OverlayEntry::File {
+                content: content.to_string(),
+            },
+        );
+        Ok(())
+    }
+
+    /// Create an empty directory. Recorded in the overlay.
+    pub fn create_dir(&mut self, path: &str) -> Result<(), FsError> {
+        if self.nodes.contains_key(path) {
+            return Err(FsError::AlreadyExists);
+        }
+        if self.is_read_only(path) {
+            return Err(FsError::PermissionDenied);
+        }
+        self.attach_to_parent(path)?;
+        self.add_dir(path, &[]);
+        self.overlay.record(path, OverlayEntry::Dir);
+        Ok(())
+    }
+
+    /// Remove a file or an empty directory. Recorded in the overlay.
+    pub fn remove(&mut self, path: &str) -> Result<(), FsError> {
+        match self.nodes.get(path) {
+            None => return Err(FsError::NotFound),
+            Some(VfsNode::Directory { children, .. }) if !children.is_empty() => {
+                return Err(FsError::DirectoryNotEmpty)
+            }
+            Some(_) if self.is_read_only(path) => return Err(FsError::PermissionDenied),
+            Some(_) => {}
+        }
+        self.remove_subtree(path);
+        self.overlay.record(path, OverlayEntry::Deleted);
+        Ok(())
+    }
+
+    /// Remove a file or a directory with everything in it. Recorded in the
+    /// overlay.
+    pub fn remove_all(&mut self, path: &str) -> Result<(), FsError> {
+        if !self.nodes.contains_key(path) {
+            return Err(FsError::NotFound);
+        }
+        // Home and /tmp themselves stay, and nothing read-only goes with them
+        let prefix = format!("{}/", path);
+        if path == HOME
+            || path == TMP
+            || self
+                .nodes
+                .keys()
+                .any(|key| (key == path || key.starts_with(&prefix)) && self.is_read_only(key))
+        {
+            return Err(FsError::PermissionDenied);
+        }
+        self.remove_subtree(path);
+        self.overlay.record(path, OverlayEntry::Deleted);
+        Ok(())
+    }
+
+    /// Replay a saved overlay on top of this (built-in) filesystem.
+    pub fn apply_overlay(&mut self, overlay: Overlay) {
+        for (path, entry) in &overlay.entries {
+            // Entries that no longer fit the built-in tree are dropped silently
+            let _ = match entry {
+                OverlayEntry::File { content } => {
+                    // A file can only stand where a built-in directory was
+                    // once that directory has been deleted
+                    if self.is_dir(path) {
+                        self.remove_subtree(path);
+                    }
+                    self.write_file(path, content)
+                }
+                OverlayEntry::Dir if self.is_dir(path) => Ok(()),
+                OverlayEntry::Dir => self.create_dir(path),
+                OverlayEntry::Recreated => {
+                    self.remove_subtree(path);
+                    self.create_dir(path)
+                }
+                OverlayEntry::Deleted => {
+                    self.remove_subtree(path);
+                    Ok(())
+                }
+            };
+        }
+        self.overlay = overlay;
+    }
+
+    /// Generated files and everything outside home and /tmp belong to the
+    /// system and can't be changed by the visitor.
+    pub fn is_read_only(&self, path: &str) -> bool {
+        !(is_within(path, HOME) || is_within(path, TMP))
+            || matches!(self.nodes.get(path), Some(VfsNode::Generated { .. }))
+    }
+
+    fn attach_to_parent(&mut self, path: &str) -> Result<(), FsError> {
+        let (parent, name) = path.rsplit_once('/').ok_or(FsError::NotFound)?;
+        match self.nodes.get_mut(parent) {
+            Some(VfsNode::Directory { children, .. }) => {
+                if !children.iter().any(|c| c == name) {
+                    children.push(name.to_string());
+                }
+                Ok(())
+            }
+            Some(_) => Err(FsError::NotADirectory),
+            None => Err(FsError::NotFound),
+        }
+    }
+
+    fn remove_subtree(&mut self, path: &str) {
+        let prefix = format!("{}/", path);
+        self.nodes
+            .retain(|key, _| key != path && !key.starts_with(&prefix));
 
-        // handle absolute paths
-        if input.starts_with("~/") || input == "~" {
-            return self.normalize(input);
+        if let Some((parent, name)) = path.rsplit_once('/') {
+            if let Some(VfsNode::Directory { children, .. }) = self.nodes.get_mut(parent) {
+                children.retain(|c| c != name);
+            }
         }
+    }
+
+    /// Every node under `path` (inclusive) as (path relative to `path`'s
+    /// parent, file bytes). Directories have no bytes and come before their
+    /// children.
+    pub fn walk(&self, path: &str) -> Vec<(String, Option<Cow<'_, [u8]>>)> {
+        // Archiving home should unpack its contents, not a "visitor" folder
+        let base = match path.rsplit_once('/') {
+            _ if path == HOME => path.len() + 1,
+            Some((parent, _)) => parent.len() + 1,
+            None => path.len() + 1,
+        };
+        let mut entries = Vec::new();
+        self.walk_into(path, base, &mut entries);
+        entries
+    }
 
-        // Handle relative paths
-        let combined = if cwd == "~" {
-            format!("~/{}", input)
+    fn walk_into<'a>(
+        &'a self,
+        path: &str,
+        base: usize,
+        entries: &mut Vec<(String, Option<Cow<'a, [u8]>>)>,
+    ) {
+        let relative = path.get(base..).unwrap_or_default().to_string();
+        match self.nodes.get(path) {
+            Some(VfsNode::Directory { children, .. }) => {
+                if !relative.is_empty() {
+                    entries.push((relative, None));
+                }
+                for child in children {
+                    self.walk_into(&join(path, child), base, entries);
+                }
+            }
+            Some(_) => entries.push((relative, self.read_bytes(path))),
+            None => {}
+        }
+    }
+
+    /// Resolve a path relative to cwd into an absolute key. Handles "~",
+    /// "..", ".", and absolute paths.
+    pub fn resolve_path(&self, cwd: &str, input: &str) -> String {
+        let input = input.trim();
+
+        let absolute = if input == "~" {
+            HOME.to_string()
+        } else if let Some(rest) = input.strip_prefix("~/") {
+            join(HOME, rest)
+        } else if input.starts_with('/') {
+            input.to_string()
         } else {
-            format!("{}/{}", cwd, input)
+            join(cwd, input)
         };
 
-        self.normalize(&combined)
+        self.normalize(&absolute)
     }
 
-    /// Normalize a path: resolve ".." and ".", remove trailing slashes
+    /// Normalize an absolute path: resolve ".." and ".", remove trailing slashes
     fn normalize(&self, path: &str) -> String {
         let mut parts: Vec<&str> = Vec::new();
 
         for part in path.split('/') {
             match part {
                 "." | "" => continue,
+                // ".." at the root stays at the root
                 ".." => {
-                    if parts.len() > 1 || (parts.len() == 1 && parts[0] != "~") {
-                        parts.pop();
-                    }
+                    parts.pop();
                 }
                 other => parts.push(other),
             }
         }
 
-        if parts.is_empty() {
-            "~".to_string()
-        } else {
-            parts.join("/")
-        }
+        format!("/{}", parts.join("/"))
     }
 
     pub fn is_dir(&self, path: &str) -> bool {
@@ -87,21 +315,25 @@ impl VirtualFs {
     }
 
     pub fn is_file(&self, path: &str) -> bool {
-        matches!(self.nodes.get(path), Some(VfsNode::File { .. }))
+        matches!(
+            self.nodes.get(path),
+            Some(VfsNode::File { .. } | VfsNode::Binary { .. } | VfsNode::Generated { .. })
+        )
+    }
+
+    pub fn is_binary(&self, path: &str) -> bool {
+        matches!(self.nodes.get(path), Some(VfsNode::Binary { .. }))
     }
 
-    pub fn ls(&self, path: &str) -> Option<Vec<(String, bool)>> {
+    /// List a directory. Dotfiles are skipped unless `show_hidden` is set.
+    pub fn ls(&self, path: &str, show_hidden: bool) -> Option<Vec<(String, bool)>> {
         match self.nodes.get(path) {
             Some(VfsNode::Directory { children, .. }) => {
                 let mut entries: Vec<(String, bool)> = children
                     .iter()
+                    .filter(|child| show_hidden || !is_hidden(child))
                     .map(|child| {
-                        let child_path = if path == "~" {
-                            format!("~/{}", child)
-                        } else {
-                            format!("{}{}", path, child)
-                        };
-                        let is_dir = self.is_dir(&child_path);
+                        let is_dir = self.is_dir(&join(path, child));
                         (child.clone(), is_dir)
                     })
                     .collect();
@@ -112,19 +344,47 @@ impl VirtualFs {
         }
     }
 
-    pub fn cat(&self, path: &str) -> Option<&str> {
+    pub fn cat(&self, path: &str) -> Option<Cow<'_, str>> {
+        match self.nodes.get(path) {
+            Some(VfsNode::File { content, .. }) => Some(Cow::Borrowed(content)),
+            Some(VfsNode::Generated { generator, .. }) => {
+                Some(Cow::Owned(generator.render(&self.session())))
+            }
+            _ => None,
+        }
+    }
+
+    fn session(&self) -> Session {
+        self.session
+            .map(|source| source.capture())
+            .unwrap_or_default()
+    }
+
+    pub fn read_bytes(&self, path: &str) -> Option<Cow<'_, [u8]>> {
         match self.nodes.get(path) {
-            Some(VfsNode::File { content, .. }) => Some(content),
+            Some(VfsNode::File { content, .. }) => Some(Cow::Borrowed(content.as_bytes())),
+            Some(VfsNode::Binary { data, .. }) => Some(Cow::Borrowed(data)),
+            Some(VfsNode::Generated { generator, .. }) => {
+                Some(Cow::Owned(generator.render(&self.session()).into_bytes()))
+            }
             _ => None,
         }
     }
 
-    pub fn tree(&self, path: &str, prefix: &str, is_last: bool) -> Option<Vec<String>> {
+    pub fn tree(
+        &self,
+        path: &str,
+        prefix: &str,
+        is_last: bool,
+        show_hidden: bool,
+    ) -> Option<Vec<String>> {
         let node = self.nodes.get(path)?; // return null immediately if this is None.
         let mut lines = Vec::new();
 
         let name = match node {
-            VfsNode::File { name, .. } => name.clone(),
+            VfsNode::File { name, .. }
+            | VfsNode::Binary { name, .. }
+            | VfsNode::Generated { name, .. } => name.clone(),
             VfsNode::Directory { name, .. } => format!("{}/", name),
         };
 
@@ -145,14 +405,17 @@ impl VirtualFs {
                 format!("{}│   ", prefix)
             };
 
-            for (i, child) in children.iter().enumerate() {
-                let child_path = if path == "~" {
-                    format!("~/{}", child)
-                } else {
-                    format!("{}/{}", path, child)
-                };
-                let is_last_child = i == children.len() - 1;
-                if let Some(child_lines) = self.tree(&child_path, &child_prefix, is_last_child) {
+            let visible: Vec<&String> = children
+                .iter()
+                .filter(|child| show_hidden || !is_hidden(child))
+                .collect();
+
+            for (i, child) in visible.iter().enumerate() {
+                let child_path = join(path, child);
+                let is_last_child = i == visible.len() - 1;
+                if let Some(child_lines) =
+                    self.tree(&child_path, &child_prefix, is_last_child, show_hidden)
+                {
                     lines.extend(child_lines);
                 }
             }
@@ -161,3 +424,32 @@ impl VirtualFs {
         Some(lines)
     }
 }
+
+/// Dotfiles stay out of listings unless explicitly asked for.
+pub fn is_hidden(name: &str) -> bool {
+    name.starts_with('.')
+}
+
+/// Show a path the way the prompt does, with home abbreviated to `~`.
+pub fn display_path(path: &str) -> String {
+    match path.strip_prefix(HOME) {
+        Some("") => "~".to_string(),
+        Some(rest) if rest.starts_with('/') => format!("~{}", rest),
+        _ => path.to_string(),
+    }
+}
+
+/// Whether `path` is `dir` itself or somewhere below it.
+fn is_within(path: &str, dir: &str) -> bool {
+    path.strip_prefix(dir)
+        .is_some_and(|rest| rest.is_empty() || rest.starts_with('/'))
+}
+
+/// Path of `child` inside directory `parent`.
+pub fn join(parent: &str, child: &str) -> String {
+    if parent == "/" {
+        format!("/{}", child)
+    } else {
+        format!("{}/{}", parent, child)
+    }
+}
@@ -0,0 +1,69 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+const STORAGE_KEY: &str = "portfolio.vfs-overlay";
+
+/// A change the visitor made on top of the built-in filesystem.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "lowercase")]
+pub enum OverlayEntry {
+    File {
+        content: String,
+    },
+    Dir,
+    /// A directory deleted and then created again, so nothing the built-in
+    /// tree had there comes back
+    Recreated,
+    Deleted,
+}
+
+/// Copy-on-write layer over the default filesystem. The built-in tree is
+/// rebuilt on every load and these entries are replayed on top of it, so only
+/// the visitor's own changes ever end up in localStorage.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Overlay {
+    // BTreeMap keeps parents ordered before their children when replaying
+    pub entries: BTreeMap<String, OverlayEntry>,
+}
+
+impl Overlay {
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn record(&mut self, path: &str, entry: OverlayEntry) {
+        if entry == OverlayEntry::Deleted {
+            // Anything recorded below a deleted path is gone with it
+            let prefix = format!("{}/", path);
+            self.entries.retain(|key, _| !key.starts_with(&prefix));
+        }
+        let entry = match (self.entries.get(path), entry) {
+            (Some(OverlayEntry::Deleted), OverlayEntry::Dir) => OverlayEntry::Recreated,
+            (_, entry) => entry,
+        };
+        self.entries.insert(path.to_string(), entry);
+    }
+}
+
+fn local_storage() -> Option<web_sys::Storage> {
+    web_sys::window()?.local_storage().ok()?
+}
+
+pub fn load() -> Overlay {
+    local_storage()
+        .and_then(|storage| storage.get_item(STORAGE_KEY).ok()?)
+        .and_then(|json| serde_json::from_str(&json).ok())
+        .unwrap_or_default()
+}
+
+pub fn save(overlay: &Overlay) {
+    let Some(storage) = local_storage() else {
+        return;
+    };
+
+    if overlay.is_empty() {
+        let _ = storage.remove_item(STORAGE_KEY);
+    } else if let Ok(json) = serde_json::to_string(overlay) {
+        let _ = storage.set_item(STORAGE_KEY, &json);
+    }
+}
@@ -0,0 +1,109 @@
+use crate::state::theme::ThemeState;
+use leptos::prelude::*;
+
+/// Files whose content is computed each time they are read.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Generator {
+    Uptime,
+    History,
+    Theme,
+    Hostname,
+    CpuInfo,
+}
+
+/// Shell state the generators read from, captured when one of them runs.
+#[derive(Clone, Debug, Default)]
+pub struct Session {
+    pub history: Vec<String>,
+    pub theme: String,
+    pub crt: bool,
+    pub matrix_rain: bool,
+}
+
+impl Session {
+    pub fn capture(theme: &ThemeState, history: &[String]) -> Self {
+        Self {
+            history: history.to_vec(),
+            theme: theme.color_scheme.get_untracked().name().to_string(),
+            crt: theme.crt_enabled.get_untracked(),
+            matrix_rain: theme.matrix_rain.get_untracked(),
+        }
+    }
+}
+
+/// The live theme and history, read only when a generated file is, so
+/// running a command doesn't copy the history into the filesystem.
+#[derive(Clone, Copy)]
+pub struct SessionSource {
+    pub theme: ThemeState,
+    pub history: ReadSignal<Vec<String>>,
+}
+
+impl SessionSource {
+    pub fn capture(&self) -> Session {
+        self.history
+            .with_untracked(|history| Session::capture(&self.theme, history))
+    }
+}
+
+impl std::fmt::Debug for SessionSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("SessionSource")
+    }
+}
+
+impl Generator {
+    pub fn render(&self, session: &Session) -> String {
+        match self {
+            Generator::Uptime => {
+                // performance.now() counts from page load, which is our boot time
+                let millis = web_sys::window()
+                    .and_then(|w| w.performance())
+                    .map(|p| p.now())
+                    .unwrap_or_default();
+                format!("{:.2} 0.00\n", millis / 1000.0)
+            }
+            Generator::History => session
+                .history
+                .iter()
+                .enumerate()
+                .map(|(i, cmd)| format!("{:>5}  {}\n", i + 1, cmd))
+                .collect(),
+            Generator::Theme => format!(
+                "theme = {}\ncrt = {}\nmatrix = {}\n",
+                session.theme,
+                on_off(session.crt),
+                on_off(session.matrix_rain)
+            ),
+            Generator::Hostname => {
+                let host = web_sys::window()
+                    .and_then(|w| w.location().hostname().ok())
+                    .filter(|h| !h.is_empty())
+                    .unwrap_or_else(|| "portfolio".to_string());
+                format!("{}\n", host)
+            }
+            Generator::CpuInfo => {
+                let cores = web_sys::window()
+                    .map(|w| w.navigator().hardware_concurrency() as usize)
+                    .unwrap_or(1)
+                    .max(1);
+                (0..cores)
+                    .map(|i| {
+                        format!(
+                            "processor\t: {}\nmodel name\t: WebAssembly virtual CPU\ncpu cores\t: {}\n\n",
+                            i, cores
+                        )
+                    })
+                    .collect()
+            }
+        }
+    }
+}
+
+fn on_off(value: bool) -> &'static str {
+    if value {
+        "on"
+    } else {
+        "off"
+    }
+}
@@ -0,0 +1,126 @@
+use std::collections::HashMap;
+use std::sync::{OnceLock, RwLock};
+
+const STORAGE_KEY: &str = "portfolio.lang";
+
+/// Languages the shell speaks, as (code, name in that language).
+pub const LOCALES: &[(&str, &str)] = &[("en", "English"), ("es", "Español"), ("fr", "Français")];
+
+/// The complete catalog, used for any message a locale doesn't translate.
+pub const FALLBACK: &str = "en";
+
+const CATALOGS: &[(&str, &str)] = &[
+    ("en", include_str!("../../content/locales/en/messages.json")),
+    ("es", include_str!("../../content/locales/es/messages.json")),
+    ("fr", include_str!("../../content/locales/fr/messages.json")),
+];
+
+static CURRENT: RwLock<&str> = RwLock::new(FALLBACK);
+
+fn catalogs() -> &'static HashMap<&'static str, HashMap<String, String>> {
+    static PARSED: OnceLock<HashMap<&'static str, HashMap<String, String>>> = OnceLock::new();
+    PARSED.get_or_init(|| {
+        CATALOGS
+            .iter()
+            .map(|(code, source)| {
+                let messages = serde_json::from_str(source)
+                    .expect("content/locales/*/messages.json should map keys to strings");
+                (*code, messages)
+            })
+            .collect()
+    })
+}
+
+pub fn current() -> &'static str {
+    *CURRENT.read().unwrap()
+}
+
+/// Match a browser language tag such as "es-MX" to a supported locale.
+pub fn negotiate(tag: &str) -> Option<&'static str> {
+    let primary = tag.split(['-', '_']).next()?.to_lowercase();
+    LOCALES
+        .iter()
+        .map(|(code, _)| *code)
+        .find(|code| *code == primary)
+}
+
+/// The name of a locale in its own language.
+pub fn name(code: &str) -> &'static str {
+    LOCALES
+        .iter()
+        .find(|(c, _)| *c == code)
+        .map(|(_, name)| *name)
+        .unwrap_or("English")
+}
+
+fn local_storage() -> Option<web_sys::Storage> {
+    web_sys::window()?.local_storage().ok()?
+}
+
+/// Start in the language the visitor chose last time, or else the
+/// browser's preferred one.
+pub fn init() {
+    let saved = local_storage().and_then(|storage| storage.get_item(STORAGE_KEY).ok()?);
+    let browser = web_sys::window().and_then(|window| window.navigator().language());
+    let code = [saved, browser]
+        .into_iter()
+        .flatten()
+        .find_map(|tag| negotiate(&tag))
+        .unwrap_or(FALLBACK);
+    *CURRENT.write().unwrap() = code;
+}
+
+/// Switch language and remember the choice for the next visit.
+pub fn set(code: &'static str) {
+    *CURRENT.write().unwrap() = code;
+    if let Some(storage) = local_storage() {
+        let _ = storage.set_item(STORAGE_KEY, code);
+    }
+}
+
+fn lookup(key: &str) -> Option<&'static str> {
+    let catalogs = catalogs();
+    [current(), FALLBACK]
+        .iter()
+        .find_map(|code| catalogs.get(code)?.get(key))
+        .map(String::as_str)
+}
+
+/// The message for `key` in the current language. Missing keys fall back to
+/// English, then to the key itself so they are easy to spot.
+pub fn t(key: &str) -> String {
+    tr(key, &[])
+}
+
+/// Like `t`, filling `{name}` placeholders from `args`. Values are inserted
+/// as they are, even if they contain braces themselves.
+pub fn tr(key: &str, args: &[(&str, &str)]) -> String {
+    let mut rest = lookup(key).unwrap_or(key);
+    let mut message = String::with_capacity(rest.len());
+    while let Some(open) = rest.find('{') {
+        message.push_str(&rest[..open]);
+        let placeholder = rest[open..].find('}').and_then(|close| {
+            let name = &rest[open + 1..open + close];
+            let (_, value) = args.iter().find(|(arg, _)| *arg == name)?;
+            Some((value, open + close + 1))
+        });
+        match placeholder {
+            Some((value, end)) => {
+                message.push_str(value);
+                rest = &rest[end..];
+            }
+            None => {
+                message.push('{');
+                rest = &rest[open + 1..];
+            }
+        }
+    }
+    message.push_str(rest);
+    message
+}
+
+/// The translation of `key`, or `default` when no catalog has one. For text
+/// whose English lives next to the code, like command descriptions.
+pub fn t_or(key: &str, default: &str) -> String {
+    lookup(key).unwrap_or(default).to_string()
+}
@@ -2,7 +2,9 @@ mod app;
 mod commands;
 mod components;
 mod filesystem;
+mod i18n;
 mod parser;
+mod portfolio;
 mod state;
 
 fn main() {
@@ -0,0 +1,188 @@
+use crate::commands::LineStyle;
+
+/// Render markdown source into styled terminal lines. Inline links are
+/// numbered like footnotes and listed as clickable references at the end.
+pub fn render(source: &str) -> Vec<(String, LineStyle)> {
+    let mut lines = Vec::new();
+    let mut links: Vec<String> = Vec::new();
+    let mut in_code_block = false;
+
+    for raw in source.lines() {
+        let trimmed = raw.trim_start();
+
+        if trimmed.starts_with("```") {
+            in_code_block = !in_code_block;
+            continue;
+        }
+
+        if in_code_block {
+            lines.push((format!("    {}", raw), LineStyle::Code));
+            continue;
+        }
+
+        if trimmed.is_empty() {
+            lines.push((String::new(), LineStyle::Normal));
+            continue;
+        }
+
+        if let Some((level, heading)) = heading(trimmed) {
+            let (text, _) = inline(heading, &mut links);
+            let underline = match level {
+                1 => Some("═"),
+                2 => Some("─"),
+                _ => None,
+            };
+            let width = text.chars().count();
+            lines.push((text, LineStyle::Accent));
+            if let Some(ch) = underline {
+                lines.push((ch.repeat(width), LineStyle::Accent));
+            }
+            continue;
+        }
+
+        if is_rule(trimmed) {
+            lines.push(("─".repeat(40), LineStyle::Muted));
+            continue;
+        }
+
+        if let Some(quote) = trimmed.strip_prefix('>') {
+            let (text, _) = inline(quote.trim_start(), &mut links);
+            lines.push((format!("  │ {}", text), LineStyle::Muted));
+            continue;
+        }
+
+        let depth = (raw.len() - trimmed.len()) / 2;
+        let indent = "  ".repeat(depth + 1);
+
+        if let Some(item) = bullet_item(trimmed) {
+            let glyph = if depth == 0 { "•" } else { "◦" };
+            let (text, _) = inline(item, &mut links);
+            lines.push((format!("{}{} {}", indent, glyph, text), LineStyle::Normal));
+            continue;
+        }
+
+        if let Some((number, item)) = ordered_item(trimmed) {
+            let (text, _) = inline(item, &mut links);
+            lines.push((format!("{}{}. {}", indent, number, text), LineStyle::Normal));
+            continue;
+        }
+
+        let (text, emphasized) = inline(trimmed, &mut links);
+        let style = if emphasized {
+            LineStyle::Emphasis
+        } else {
+            LineStyle::Normal
+        };
+        lines.push((text, style));
+    }
+
+    if !links.is_empty() {
+        lines.push((String::new(), LineStyle::Normal));
+        lines.push(("References".to_string(), LineStyle::Muted));
+        for (i, href) in links.into_iter().enumerate() {
+            lines.push((format!("  [{}] {}", i + 1, href), LineStyle::Link(href)));
+        }
+    }
+
+    lines
+}
+
+fn heading(line: &str) -> Option<(usize, &str)> {
+    let level = line.chars().take_while(|&c| c == '#').count();
+    if (1..=6).contains(&level) && line[level..].starts_with(' ') {
+        Some((level, line[level..].trim()))
+    } else {
+        None
+    }
+}
+
+fn is_rule(line: &str) -> bool {
+    let compact: String = line.chars().filter(|c| !c.is_whitespace()).collect();
+    compact.len() >= 3
+        && ["-", "*", "_"]
+            .iter()
+            .any(|m| compact.chars().all(|c| c.to_string() == *m))
+}
+
+fn bullet_item(line: &str) -> Option<&str> {
+    ["- ", "* ", "+ "]
+        .iter()
+        .find_map(|marker| line.strip_prefix(marker))
+}
+
+fn ordered_item(line: &str) -> Option<(&str, &str)> {
+    let digits = line.chars().take_while(|c| c.is_ascii_digit()).count();
+    if digits == 0 {
+        return None;
+    }
+    let item = line[digits..].strip_prefix(". ")?;
+    Some((&line[..digits], item))
+}
+
+/// Strip inline markup. Returns the plain text and whether the whole line
+/// was wrapped in emphasis (e.g. `**Features:**`).
+fn inline(text: &str, links: &mut Vec<String>) -> (String, bool) {
+    let chars: Vec<char> = text.chars().collect();
+    let mut out = String::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        match chars[i] {
+            '`' => {
+                if let Some(end) = find(&chars, i + 1, "`") {
+                    out.extend(&chars[i + 1..end]);
+                    i = end + 1;
+                    continue;
+                }
+            }
+            '[' => {
+                if let Some(close) = find(&chars, i + 1, "](") {
+                    if let Some(end) = find(&chars, close + 2, ")") {
+                        let label: String = chars[i + 1..close].iter().collect();
+                        let href: String = chars[close + 2..end].iter().collect();
+                        links.push(href);
+                        out.push_str(&format!("{}[{}]", label, links.len()));
+                        i = end + 1;
+                        continue;
+                    }
+                }
+            }
+            '*' | '_' => {
+                let marker = chars[i];
+                let width = if chars.get(i + 1) == Some(&marker) {
+                    2
+                } else {
+                    1
+                };
+                let at_word_start = i == 0 || !chars[i - 1].is_alphanumeric();
+                if marker == '*' || at_word_start {
+                    let closing: String = std::iter::repeat_n(marker, width).collect();
+                    if let Some(end) = find(&chars, i + width, &closing) {
+                        if end > i + width {
+                            let (inner, _) =
+                                inline(&chars[i + width..end].iter().collect::<String>(), links);
+                            out.push_str(&inner);
+                            i = end + width;
+                            continue;
+                        }
+                    }
+                }
+            }
+            _ => {}
+        }
+        out.push(chars[i]);
+        i += 1;
+    }
+
+    let emphasized = ["**", "__", "*", "_"].iter().any(|m| {
+        text.len() > 2 * m.len()
+            && text.starts_with(m)
+            && text[m.len()..].find(m) == Some(text.len() - 2 * m.len())
+    });
+    (out, emphasized)
+}
+
+fn find(chars: &[char], from: usize, pattern: &str) -> Option<usize> {
+    let pattern: Vec<char> = pattern.chars().collect();
+    (from..chars.len()).find(|&i| chars[i..].starts_with(&pattern))
+}
@@ -1 +1,2 @@
+pub mod markdown;
 pub mod tokenizer;
\ No newline at end of file
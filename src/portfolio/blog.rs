@@ -0,0 +1,164 @@
+use std::sync::OnceLock;
+
+/// Posts shipped with the site, by slug. Each file starts with a frontmatter
+/// block between `---` lines.
+pub const SOURCES: &[(&str, &str)] = &[(
+    "sample-post",
+    include_str!("../../content/blog/sample-post.md"),
+)];
+
+#[derive(Clone, Debug)]
+pub struct Post {
+    pub slug: String,
+    pub title: String,
+    /// "YYYY-MM-DD", or empty when the frontmatter has none
+    pub date: String,
+    pub tags: Vec<String>,
+    pub summary: Option<String>,
+    /// The markdown after the frontmatter
+    pub body: String,
+}
+
+impl Post {
+    /// The summary from the frontmatter, or else the first paragraph.
+    pub fn blurb(&self) -> String {
+        match &self.summary {
+            Some(summary) => summary.clone(),
+            None => self
+                .body
+                .split("\n\n")
+                .map(|paragraph| paragraph.split_whitespace().collect::<Vec<_>>().join(" "))
+                .find(|paragraph| !paragraph.is_empty() && !paragraph.starts_with('#'))
+                .unwrap_or_default(),
+        }
+    }
+}
+
+/// Read a post. Missing frontmatter is fine: the slug stands in for the
+/// title and the post has no date or tags.
+pub fn parse(slug: &str, source: &str) -> Post {
+    let mut post = Post {
+        slug: slug.to_string(),
+        title: slug.to_string(),
+        date: String::new(),
+        tags: Vec::new(),
+        summary: None,
+        body: source.to_string(),
+    };
+
+    let Some(rest) = source.strip_prefix("---\n") else {
+        return post;
+    };
+    let Some((frontmatter, body)) = rest.split_once("\n---") else {
+        return post;
+    };
+    post.body = body.trim_start_matches('\n').to_string();
+
+    for line in frontmatter.lines() {
+        let Some((key, value)) = line.split_once(':') else {
+            continue;
+        };
+        let value = value.trim();
+        match key.trim() {
+            "title" => post.title = value.to_string(),
+            "date" => post.date = value.to_string(),
+            "summary" => post.summary = Some(value.to_string()),
+            "tags" => {
+                post.tags = value
+                    .trim_matches(['[', ']'])
+                    .split(',')
+                    .map(|tag| tag.trim().to_lowercase())
+                    .filter(|tag| !tag.is_empty())
+                    .collect()
+            }
+            _ => {}
+        }
+    }
+    post
+}
+
+/// The posts shipped with the site, newest first.
+pub fn posts() -> &'static [Post] {
+    static POSTS: OnceLock<Vec<Post>> = OnceLock::new();
+    POSTS.get_or_init(|| {
+        let mut posts: Vec<Post> = SOURCES
+            .iter()
+            .map(|(slug, source)| parse(slug, source))
+            .collect();
+        posts.sort_by(|a, b| b.date.cmp(&a.date));
+        posts
+    })
+}
+
+/// An RSS 2.0 feed of the posts, for a site served from `site`.
+pub fn rss(posts: &[Post], site: &str) -> String {
+    let site = site.trim_end_matches('/');
+    let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    xml.push_str("<rss version=\"2.0\">\n<channel>\n");
+    xml.push_str("  <title>~/blog</title>\n");
+    xml.push_str(&format!("  <link>{}/</link>\n", escape(site)));
+    xml.push_str("  <description>Short write-ups from the terminal portfolio</description>\n");
+
+    for post in posts {
+        xml.push_str("  <item>\n");
+        xml.push_str(&format!("    <title>{}</title>\n", escape(&post.title)));
+        let permalink = escape(&permalink(site, &post.slug));
+        xml.push_str(&format!("    <link>{}</link>\n", permalink));
+        xml.push_str(&format!(
+            "    <guid isPermaLink=\"true\">{}</guid>\n",
+            permalink
+        ));
+        if let Some(date) = rfc822(&post.date) {
+            xml.push_str(&format!("    <pubDate>{}</pubDate>\n", date));
+        }
+        for tag in &post.tags {
+            xml.push_str(&format!("    <category>{}</category>\n", escape(tag)));
+        }
+        xml.push_str(&format!(
+            "    <description>{}</description>\n",
+            escape(&post.blurb())
+        ));
+        xml.push_str("  </item>\n");
+    }
+
+    xml.push_str("</channel>\n</rss>\n");
+    xml
+}
+
+/// Where a post can be read: the site opens it with `blog read <slug>`.
+pub fn permalink(site: &str, slug: &str) -> String {
+    format!("{}/#blog/{}", site.trim_end_matches('/'), slug)
+}
+
+/// "2026-05-09" as "Sat, 09 May 2026 00:00:00 +0000".
+fn rfc822(date: &str) -> Option<String> {
+    const DAYS: [&str; 7] = ["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"];
+    const MONTHS: [&str; 12] = [
+        "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+    ];
+    let mut parts = date.splitn(3, '-').map(|part| part.parse::<usize>().ok());
+    let (year, month, day) = (parts.next()??, parts.next()??, parts.next()??);
+    if year == 0 || !(1..=12).contains(&month) || !(1..=31).contains(&day) {
+        return None;
+    }
+
+    // Sakamoto's method for the day of the week
+    const OFFSETS: [usize; 12] = [0, 3, 2, 5, 0, 3, 5, 1, 4, 6, 2, 4];
+    let y = if month < 3 { year - 1 } else { year };
+    let weekday = (y + y / 4 - y / 100 + y / 400 + OFFSETS[month - 1] + day) % 7;
+
+    Some(format!(
+        "{}, {:02} {} {} 00:00:00 +0000",
+        DAYS[weekday],
+        day,
+        MONTHS[month - 1],
+        year
+    ))
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
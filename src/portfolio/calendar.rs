@@ -0,0 +1,51 @@
+use super::vcard::{content_lines, escape};
+
+/// A proposed meeting. Times are UTC, as "YYYYMMDDTHHMMSSZ".
+pub struct Invite {
+    pub uid: String,
+    /// When the invite was made
+    pub stamp: String,
+    pub start: String,
+    pub end: String,
+    pub summary: String,
+    pub description: String,
+    /// (name, email) of whoever proposed it
+    pub organizer: (String, String),
+    /// (name, email) of who it's for
+    pub attendee: (String, String),
+}
+
+/// An iCalendar meeting request that calendar apps can add and reply to.
+pub fn ics(invite: &Invite, product: &str) -> String {
+    let lines = [
+        "BEGIN:VCALENDAR".to_string(),
+        "VERSION:2.0".to_string(),
+        format!("PRODID:-//{}//EN", product),
+        "METHOD:REQUEST".to_string(),
+        "BEGIN:VEVENT".to_string(),
+        format!("UID:{}", invite.uid),
+        format!("DTSTAMP:{}", invite.stamp),
+        format!("DTSTART:{}", invite.start),
+        format!("DTEND:{}", invite.end),
+        format!("SUMMARY:{}", escape(&invite.summary)),
+        format!("DESCRIPTION:{}", escape(&invite.description)),
+        format!(
+            "ORGANIZER;CN=\"{}\":mailto:{}",
+            common_name(&invite.organizer.0),
+            invite.organizer.1
+        ),
+        format!(
+            "ATTENDEE;CN=\"{}\";ROLE=REQ-PARTICIPANT;RSVP=TRUE:mailto:{}",
+            common_name(&invite.attendee.0),
+            invite.attendee.1
+        ),
+        "END:VEVENT".to_string(),
+        "END:VCALENDAR".to_string(),
+    ];
+    content_lines(&lines)
+}
+
+/// Quoted parameter values can hold anything but a double quote.
+fn common_name(name: &str) -> String {
+    name.replace('"', "'")
+}
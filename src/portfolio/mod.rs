@@ -0,0 +1,80 @@
+pub mod blog;
+pub mod calendar;
+pub mod model;
+pub mod resume;
+pub mod text;
+pub mod vcard;
+
+use crate::i18n;
+use model::Portfolio;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+const SOURCE: &str = include_str!("../../content/portfolio.json");
+
+/// Per-locale overlays on the English content. Each mirrors the shape of
+/// portfolio.json but only holds what it translates; list entries line up by
+/// position, and `{}` leaves an entry as it is.
+const TRANSLATIONS: &[(&str, &str)] = &[
+    (
+        "es",
+        include_str!("../../content/locales/es/portfolio.json"),
+    ),
+    (
+        "fr",
+        include_str!("../../content/locales/fr/portfolio.json"),
+    ),
+];
+
+/// The portfolio content in the current language, parsed once per locale.
+pub fn portfolio() -> &'static Portfolio {
+    static PORTFOLIOS: OnceLock<HashMap<&'static str, Portfolio>> = OnceLock::new();
+    let portfolios = PORTFOLIOS.get_or_init(|| {
+        let base: Value =
+            serde_json::from_str(SOURCE).expect("content/portfolio.json should be valid JSON");
+        let mut portfolios = HashMap::new();
+        portfolios.insert(i18n::FALLBACK, parse(base.clone()));
+        for (code, source) in TRANSLATIONS {
+            let patch = serde_json::from_str(source)
+                .expect("content/locales/*/portfolio.json should be valid JSON");
+            let mut content = base.clone();
+            merge(&mut content, patch);
+            portfolios.insert(*code, parse(content));
+        }
+        portfolios
+    });
+    portfolios
+        .get(i18n::current())
+        .unwrap_or(&portfolios[i18n::FALLBACK])
+}
+
+fn parse(content: Value) -> Portfolio {
+    serde_json::from_value(content).expect("content/portfolio.json should match the model")
+}
+
+/// Lay `patch` over `base`: objects merge by key, arrays by index, and
+/// anything else is replaced.
+fn merge(base: &mut Value, patch: Value) {
+    match (base, patch) {
+        (Value::Object(base), Value::Object(patch)) => {
+            for (key, value) in patch {
+                match base.get_mut(&key) {
+                    Some(existing) => merge(existing, value),
+                    None => {
+                        base.insert(key, value);
+                    }
+                }
+            }
+        }
+        (Value::Array(base), Value::Array(patch)) => {
+            for (i, value) in patch.into_iter().enumerate() {
+                match base.get_mut(i) {
+                    Some(existing) => merge(existing, value),
+                    None => base.push(value),
+                }
+            }
+        }
+        (base, patch) => *base = patch,
+    }
+}
@@ -0,0 +1,121 @@
+use serde::de::Error;
+use serde::{Deserialize, Deserializer, Serialize};
+
+/// Everything the site says about its owner, loaded from
+/// `content/portfolio.json`.
+#[derive(Clone, Debug, Deserialize)]
+pub struct Portfolio {
+    pub profile: Profile,
+    pub contact: Vec<ContactMethod>,
+    pub projects: Vec<Project>,
+    pub skills: Vec<Skill>,
+    pub experience: Vec<Experience>,
+    #[serde(default)]
+    pub education: Vec<Education>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct Profile {
+    pub name: String,
+    pub first_name: String,
+    pub title: String,
+    pub tagline: String,
+    /// Paragraphs for about.txt
+    pub about: Vec<String>,
+    pub focus: String,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct ContactMethod {
+    pub label: String,
+    pub value: String,
+    pub url: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Project {
+    /// Directory name under ~/projects
+    pub slug: String,
+    pub name: String,
+    pub summary: String,
+    /// What I did on the project
+    pub role: String,
+    pub description: String,
+    /// Year and month, "YYYY-MM"
+    pub date: String,
+    pub status: String,
+    pub tech: Vec<String>,
+    pub tags: Vec<String>,
+    pub url: Option<String>,
+    pub repo: Option<String>,
+    pub features: Vec<String>,
+    /// Command to run it locally, if it's open source
+    pub run: Option<String>,
+    /// Bundled image under assets/screenshots, copied into the project folder
+    pub screenshot: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct Skill {
+    pub name: String,
+    /// One of languages, frontend, mobile, backend, tools
+    pub category: String,
+    /// Self-assessed proficiency out of 100
+    #[serde(deserialize_with = "number_or_todo")]
+    pub level: Option<u8>,
+    #[serde(deserialize_with = "number_or_todo")]
+    pub years: Option<u8>,
+}
+
+/// A number, or a "TODO: ..." note where the owner hasn't filled it in yet.
+fn number_or_todo<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<u8>, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Field {
+        Number(u8),
+        Todo(String),
+    }
+    match Field::deserialize(deserializer)? {
+        Field::Number(number) => Ok(Some(number)),
+        Field::Todo(note) if note.starts_with("TODO") => Ok(None),
+        Field::Todo(note) => Err(D::Error::custom(format!(
+            "expected a number or a TODO note, found '{}'",
+            note
+        ))),
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct Experience {
+    pub company: String,
+    pub role: String,
+    /// Year and month, "YYYY-MM"
+    pub start: String,
+    /// `None` for the current position
+    pub end: Option<String>,
+    pub summary: String,
+    pub highlights: Vec<String>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct Education {
+    pub school: String,
+    pub degree: String,
+    /// Year and month, "YYYY-MM"
+    pub start: String,
+    pub end: Option<String>,
+}
+
+impl Skill {
+    /// Human label for a proficiency level, as used in the skill files.
+    /// Empty until a level is filled in.
+    pub fn proficiency(&self) -> &'static str {
+        match self.level {
+            Some(85..) => "Advanced",
+            Some(65..) => "Proficient",
+            Some(45..) => "Intermediate",
+            Some(_) => "Familiar",
+            None => "",
+        }
+    }
+}
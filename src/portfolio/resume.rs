@@ -0,0 +1,425 @@
+use super::model::Portfolio;
+use super::text::{self, CATEGORIES};
+use crate::commands::LineStyle;
+use crate::i18n;
+
+/// Layout-neutral pieces of the resume, shared by every output format.
+enum Block {
+    Name(String),
+    Tagline(String),
+    Heading(String),
+    /// A job, project or school: what it was, and when
+    Entry {
+        title: String,
+        meta: String,
+    },
+    Text(String),
+    Bullet(String),
+}
+
+fn blocks(portfolio: &Portfolio) -> Vec<Block> {
+    let profile = &portfolio.profile;
+    let contacts: Vec<&str> = portfolio
+        .contact
+        .iter()
+        .map(|method| method.value.as_str())
+        .collect();
+
+    let mut blocks = vec![
+        Block::Name(profile.name.clone()),
+        Block::Tagline(profile.title.clone()),
+        Block::Tagline(contacts.join(" · ")),
+        Block::Heading(i18n::t("resume.summary")),
+        Block::Text(profile.about.join(" ")),
+    ];
+
+    if !portfolio.experience.is_empty() {
+        blocks.push(Block::Heading(i18n::t("resume.experience")));
+        for job in &portfolio.experience {
+            blocks.push(Block::Entry {
+                title: format!("{} — {}", job.role, job.company),
+                meta: period(&job.start, job.end.as_deref()),
+            });
+            blocks.push(Block::Text(job.summary.clone()));
+            blocks.extend(job.highlights.iter().cloned().map(Block::Bullet));
+        }
+    }
+
+    if !portfolio.projects.is_empty() {
+        blocks.push(Block::Heading(i18n::t("resume.projects")));
+        for project in &portfolio.projects {
+            blocks.push(Block::Entry {
+                title: project.name.clone(),
+                meta: format!(
+                    "{} · {}",
+                    text::month_label(&project.date),
+                    project.tech.join(", ")
+                ),
+            });
+            blocks.push(Block::Text(project.summary.clone()));
+        }
+    }
+
+    blocks.push(Block::Heading(i18n::t("resume.skills")));
+    for (category, heading) in CATEGORIES {
+        let names: Vec<&str> = text::skills_in(portfolio, &[category])
+            .iter()
+            .map(|skill| skill.name.as_str())
+            .collect();
+        if !names.is_empty() {
+            let heading = i18n::t_or(&format!("skills.category.{}", category), heading);
+            blocks.push(Block::Text(format!("{}: {}", heading, names.join(", "))));
+        }
+    }
+
+    if !portfolio.education.is_empty() {
+        blocks.push(Block::Heading(i18n::t("resume.education")));
+        for school in &portfolio.education {
+            blocks.push(Block::Entry {
+                title: format!("{} — {}", school.degree, school.school),
+                meta: period(&school.start, school.end.as_deref()),
+            });
+        }
+    }
+
+    blocks
+}
+
+fn period(start: &str, end: Option<&str>) -> String {
+    format!(
+        "{} – {}",
+        text::month_label(start),
+        end.map(text::month_label)
+            .unwrap_or_else(|| i18n::t("resume.present"))
+    )
+}
+
+/// Styled lines for reading the resume inside the terminal.
+pub fn terminal(portfolio: &Portfolio, width: usize) -> Vec<(String, LineStyle)> {
+    let mut lines = vec![(String::new(), LineStyle::Normal)];
+    for block in blocks(portfolio) {
+        match block {
+            Block::Name(name) => lines.push((format!("  {}", name), LineStyle::Accent)),
+            Block::Tagline(tagline) => lines.push((format!("  {}", tagline), LineStyle::Muted)),
+            Block::Heading(heading) => {
+                lines.push((String::new(), LineStyle::Normal));
+                lines.push((format!("  {}", heading.to_uppercase()), LineStyle::Accent));
+                lines.push((
+                    format!("  {}", "─".repeat(width.saturating_sub(4))),
+                    LineStyle::Muted,
+                ));
+            }
+            Block::Entry { title, meta } => {
+                // Space entries apart, but not from their section heading
+                if lines
+                    .last()
+                    .is_some_and(|(_, style)| *style != LineStyle::Muted)
+                {
+                    lines.push((String::new(), LineStyle::Normal));
+                }
+                lines.push((format!("  {}", title), LineStyle::Emphasis));
+                lines.push((format!("  {}", meta), LineStyle::Muted));
+            }
+            Block::Text(body) => lines.extend(
+                wrap(&body, width.saturating_sub(4))
+                    .into_iter()
+                    .map(|line| (format!("  {}", line), LineStyle::Normal)),
+            ),
+            Block::Bullet(item) => {
+                for (i, line) in wrap(&item, width.saturating_sub(8)).into_iter().enumerate() {
+                    let marker = if i == 0 { "•" } else { " " };
+                    lines.push((format!("    {} {}", marker, line), LineStyle::Normal));
+                }
+            }
+        }
+    }
+    lines.push((String::new(), LineStyle::Normal));
+    lines
+}
+
+pub fn markdown(portfolio: &Portfolio) -> String {
+    let mut out = String::new();
+    for block in blocks(portfolio) {
+        match block {
+            Block::Name(name) => out.push_str(&format!("# {}\n\n", name)),
+            Block::Tagline(tagline) => out.push_str(&format!("{}  \n", tagline)),
+            Block::Heading(heading) => out.push_str(&format!("\n## {}\n\n", heading)),
+            Block::Entry { title, meta } => {
+                out.push_str(&format!("\n**{}**  \n*{}*\n\n", title, meta))
+            }
+            Block::Text(body) => out.push_str(&format!("{}\n\n", body)),
+            Block::Bullet(item) => out.push_str(&format!("- {}\n", item)),
+        }
+    }
+    tidy(out)
+}
+
+pub fn plain_text(portfolio: &Portfolio) -> String {
+    const WIDTH: usize = 78;
+    let mut out = String::new();
+    for block in blocks(portfolio) {
+        match block {
+            Block::Name(name) => {
+                out.push_str(&format!("{}\n{}\n", name, "=".repeat(name.chars().count())))
+            }
+            Block::Tagline(tagline) => out.push_str(&format!("{}\n", tagline)),
+            Block::Heading(heading) => out.push_str(&format!(
+                "\n{}\n{}\n",
+                heading.to_uppercase(),
+                "-".repeat(WIDTH)
+            )),
+            Block::Entry { title, meta } => out.push_str(&format!("\n{}\n{}\n", title, meta)),
+            Block::Text(body) => {
+                for line in wrap(&body, WIDTH) {
+                    out.push_str(&format!("{}\n", line));
+                }
+            }
+            Block::Bullet(item) => {
+                for (i, line) in wrap(&item, WIDTH - 4).into_iter().enumerate() {
+                    let marker = if i == 0 { "  * " } else { "    " };
+                    out.push_str(&format!("{}{}\n", marker, line));
+                }
+            }
+        }
+    }
+    tidy(out)
+}
+
+pub fn html(portfolio: &Portfolio) -> String {
+    let mut body = String::new();
+    let mut in_list = false;
+    for block in blocks(portfolio) {
+        let is_bullet = matches!(block, Block::Bullet(_));
+        if in_list && !is_bullet {
+            body.push_str("</ul>\n");
+        } else if !in_list && is_bullet {
+            body.push_str("<ul>\n");
+        }
+        in_list = is_bullet;
+
+        body.push_str(&match block {
+            Block::Name(name) => format!("<h1>{}</h1>\n", escape(&name)),
+            Block::Tagline(tagline) => format!("<p class=\"tagline\">{}</p>\n", escape(&tagline)),
+            Block::Heading(heading) => format!("<h2>{}</h2>\n", escape(&heading)),
+            Block::Entry { title, meta } => format!(
+                "<h3>{}</h3>\n<p class=\"meta\">{}</p>\n",
+                escape(&title),
+                escape(&meta)
+            ),
+            Block::Text(text) => format!("<p>{}</p>\n", escape(&text)),
+            Block::Bullet(item) => format!("<li>{}</li>\n", escape(&item)),
+        });
+    }
+    if in_list {
+        body.push_str("</ul>\n");
+    }
+
+    format!(
+        "<!DOCTYPE html>
+<html lang=\"en\">
+<head>
+<meta charset=\"utf-8\">
+<title>{} — Resume</title>
+<style>
+body {{ font-family: Georgia, serif; max-width: 46rem; margin: 3rem auto; padding: 0 1.5rem; color: #222; line-height: 1.5; }}
+h1 {{ margin-bottom: 0; }}
+h2 {{ border-bottom: 1px solid #ccc; margin-top: 2rem; color: #E07A3A; }}
+h3 {{ margin-bottom: 0; }}
+.tagline, .meta {{ color: #666; margin: 0.2rem 0; }}
+</style>
+</head>
+<body>
+{}</body>
+</html>
+",
+        escape(&portfolio.profile.name),
+        body
+    )
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Collapse runs of blank lines left between blocks.
+fn tidy(text: String) -> String {
+    let mut out = String::new();
+    let mut blank = 0;
+    for line in text.trim().lines() {
+        blank = if line.trim().is_empty() { blank + 1 } else { 0 };
+        if blank < 2 {
+            out.push_str(line);
+            out.push('\n');
+        }
+    }
+    out
+}
+
+/// Greedy word wrap to at most `width` characters per line.
+pub fn wrap(text: &str, width: usize) -> Vec<String> {
+    let mut lines = Vec::new();
+    let mut current = String::new();
+    for word in text.split_whitespace() {
+        if !current.is_empty() && current.chars().count() + 1 + word.chars().count() > width {
+            lines.push(std::mem::take(&mut current));
+        }
+        if !current.is_empty() {
+            current.push(' ');
+        }
+        current.push_str(word);
+    }
+    if !current.is_empty() {
+        lines.push(current);
+    }
+    lines
+}
+
+// US Letter, in points
+const PAGE_WIDTH: f32 = 612.0;
+const PAGE_HEIGHT: f32 = 792.0;
+const MARGIN: f32 = 56.0;
+
+/// A text-only PDF using the built-in Helvetica fonts, so nothing needs to
+/// be embedded.
+pub fn pdf(portfolio: &Portfolio) -> Vec<u8> {
+    let mut pages: Vec<String> = Vec::new();
+    let mut page = String::new();
+    let mut y = PAGE_HEIGHT - MARGIN;
+
+    // (font, size, text, extra space above)
+    let mut runs: Vec<(&str, f32, String, f32)> = Vec::new();
+    for block in blocks(portfolio) {
+        match block {
+            Block::Name(name) => runs.push(("F2", 22.0, name, 0.0)),
+            Block::Tagline(tagline) => runs.push(("F1", 10.0, tagline, 2.0)),
+            Block::Heading(heading) => runs.push(("F2", 13.0, heading.to_uppercase(), 14.0)),
+            Block::Entry { title, meta } => {
+                runs.push(("F2", 10.5, title, 8.0));
+                runs.push(("F1", 9.5, meta, 1.0));
+            }
+            Block::Text(body) => {
+                for (i, line) in wrap(&body, 90).into_iter().enumerate() {
+                    runs.push(("F1", 10.0, line, if i == 0 { 3.0 } else { 0.0 }));
+                }
+            }
+            Block::Bullet(item) => {
+                for (i, line) in wrap(&item, 86).into_iter().enumerate() {
+                    let marker = if i == 0 { "•  " } else { "    " };
+                    runs.push(("F1", 10.0, format!("{}{}", marker, line), 0.0));
+                }
+            }
+        }
+    }
+
+    for (font, size, text, space) in runs {
+        let advance = space + size * 1.35;
+        if y - advance < MARGIN {
+            pages.push(std::mem::take(&mut page));
+            y = PAGE_HEIGHT - MARGIN;
+        }
+        y -= advance;
+        page.push_str(&format!(
+            "BT /{} {} Tf {} {} Td ({}) Tj ET\n",
+            font,
+            size,
+            MARGIN,
+            y,
+            pdf_string(&text)
+        ));
+    }
+    pages.push(page);
+
+    // Objects: 1 catalog, 2 page tree, 3-4 fonts, then a page and its
+    // content stream for every page
+    let mut objects: Vec<Vec<u8>> = Vec::new();
+    let kids: Vec<String> = (0..pages.len())
+        .map(|i| format!("{} 0 R", 5 + i * 2))
+        .collect();
+    objects.push(b"<< /Type /Catalog /Pages 2 0 R >>".to_vec());
+    objects.push(
+        format!(
+            "<< /Type /Pages /Kids [{}] /Count {} >>",
+            kids.join(" "),
+            pages.len()
+        )
+        .into_bytes(),
+    );
+    for base in ["Helvetica", "Helvetica-Bold"] {
+        objects.push(
+            format!(
+                "<< /Type /Font /Subtype /Type1 /BaseFont /{} /Encoding /WinAnsiEncoding >>",
+                base
+            )
+            .into_bytes(),
+        );
+    }
+    for (i, content) in pages.iter().enumerate() {
+        objects.push(
+            format!(
+                "<< /Type /Page /Parent 2 0 R /MediaBox [0 0 {} {}] \
+                 /Resources << /Font << /F1 3 0 R /F2 4 0 R >> >> /Contents {} 0 R >>",
+                PAGE_WIDTH,
+                PAGE_HEIGHT,
+                6 + i * 2
+            )
+            .into_bytes(),
+        );
+        let mut stream = format!("<< /Length {} >>\nstream\n", content.len()).into_bytes();
+        stream.extend_from_slice(content.as_bytes());
+        stream.extend_from_slice(b"endstream");
+        objects.push(stream);
+    }
+
+    let mut out = b"%PDF-1.4\n".to_vec();
+    let mut offsets = Vec::new();
+    for (i, object) in objects.iter().enumerate() {
+        offsets.push(out.len());
+        out.extend_from_slice(format!("{} 0 obj\n", i + 1).as_bytes());
+        out.extend_from_slice(object);
+        out.extend_from_slice(b"\nendobj\n");
+    }
+
+    let xref = out.len();
+    out.extend_from_slice(
+        format!("xref\n0 {}\n0000000000 65535 f \n", objects.len() + 1).as_bytes(),
+    );
+    for offset in offsets {
+        out.extend_from_slice(format!("{:010} 00000 n \n", offset).as_bytes());
+    }
+    out.extend_from_slice(
+        format!(
+            "trailer\n<< /Size {} /Root 1 0 R >>\nstartxref\n{}\n%%EOF\n",
+            objects.len() + 1,
+            xref
+        )
+        .as_bytes(),
+    );
+    out
+}
+
+/// Escape text for a PDF string literal. Content streams are bytes in
+/// WinAnsi: Latin-1 carries straight over, and the typographic characters
+/// WinAnsi puts below it are mapped by hand.
+fn pdf_string(text: &str) -> String {
+    text.chars()
+        .map(|ch| match ch {
+            '(' | ')' | '\\' => format!("\\{}", ch),
+            '–' => "\\226".to_string(),
+            '—' => "\\227".to_string(),
+            '•' => "\\225".to_string(),
+            '‘' => "\\221".to_string(),
+            '’' => "\\222".to_string(),
+            '“' => "\\223".to_string(),
+            '”' => "\\224".to_string(),
+            '…' => "\\205".to_string(),
+            '€' => "\\200".to_string(),
+            ch if ch.is_ascii() => ch.to_string(),
+            // Latin-1 letters such as é and ñ sit at the same codes in WinAnsi
+            '\u{a0}'..='\u{ff}' => format!("\\{:03o}", ch as u32),
+            _ => "?".to_string(),
+        })
+        .collect()
+}
@@ -0,0 +1,221 @@
+use super::model::{Portfolio, Project, Skill};
+use crate::i18n;
+
+const BAR_WIDTH: usize = 22;
+
+/// Skill categories in display order, with their headings.
+pub const CATEGORIES: &[(&str, &str)] = &[
+    ("languages", "Languages"),
+    ("frontend", "Frontend"),
+    ("mobile", "Mobile"),
+    ("backend", "Backend"),
+    ("tools", "Tools"),
+];
+
+pub fn about(portfolio: &Portfolio) -> String {
+    let profile = &portfolio.profile;
+    let mut text = i18n::tr("about.greeting", &[("name", &profile.first_name)]);
+    text.push('\n');
+    if let Some((first, rest)) = profile.about.split_first() {
+        text.push_str(first);
+        text.push_str("\n\n");
+        for paragraph in rest {
+            text.push_str(paragraph);
+            text.push('\n');
+        }
+    }
+    text.push('\n');
+    text.push_str(&i18n::t("about.file_hint"));
+    text
+}
+
+pub fn contact(portfolio: &Portfolio) -> String {
+    portfolio
+        .contact
+        .iter()
+        .map(|method| format!("{:<10}{}", format!("{}:", method.label), method.value))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// A filled/empty bar for a 0-100 level.
+pub fn bar(level: u8, width: usize) -> String {
+    let filled = (level.min(100) as usize * width + 50) / 100;
+    format!("{}{}", "█".repeat(filled), "░".repeat(width - filled))
+}
+
+pub fn skills_in<'a>(portfolio: &'a Portfolio, categories: &[&str]) -> Vec<&'a Skill> {
+    portfolio
+        .skills
+        .iter()
+        .filter(|skill| categories.contains(&skill.category.as_str()))
+        .collect()
+}
+
+pub fn languages(portfolio: &Portfolio) -> String {
+    let lines: Vec<String> = skills_in(portfolio, &["languages"])
+        .iter()
+        .map(|skill| {
+            format!(
+                "{:<12}{}  {}",
+                skill.name,
+                bar(skill.level.unwrap_or(0), BAR_WIDTH),
+                skill.proficiency()
+            )
+        })
+        .collect();
+    titled("Programming Languages", &lines)
+}
+
+pub fn frameworks(portfolio: &Portfolio) -> String {
+    let lines: Vec<String> = skills_in(portfolio, &["frontend", "mobile", "backend"])
+        .iter()
+        .map(|skill| {
+            format!(
+                "{:<20}{:<10}{}",
+                skill.name,
+                category_heading(&skill.category),
+                skill.years.map(years).unwrap_or_default()
+            )
+            .trim_end()
+            .to_string()
+        })
+        .collect();
+    titled("Frameworks & Libraries", &lines)
+}
+
+pub fn tools(portfolio: &Portfolio) -> String {
+    let lines: Vec<String> = skills_in(portfolio, &["tools"])
+        .iter()
+        .map(|skill| {
+            format!(
+                "{:<20}{:<14}{}",
+                skill.name,
+                skill.proficiency(),
+                skill.years.map(years).unwrap_or_default()
+            )
+            .trim_end()
+            .to_string()
+        })
+        .collect();
+    titled("Tools & Infrastructure", &lines)
+}
+
+pub fn experience(portfolio: &Portfolio) -> String {
+    let mut lines = Vec::new();
+    for job in &portfolio.experience {
+        if !lines.is_empty() {
+            lines.push(String::new());
+        }
+        lines.push(format!("{}, {}", job.role, job.company));
+        lines.push(format!(
+            "{} - {}",
+            job.start,
+            job.end.as_deref().unwrap_or("present")
+        ));
+        lines.push(format!("  {}", job.summary));
+        for highlight in &job.highlights {
+            lines.push(format!("  - {}", highlight));
+        }
+    }
+    titled("Experience", &lines)
+}
+
+pub fn years(count: u8) -> String {
+    match count {
+        1 => "1 yr".to_string(),
+        n => format!("{} yrs", n),
+    }
+}
+
+pub fn category_heading(category: &str) -> &str {
+    CATEGORIES
+        .iter()
+        .find(|(key, _)| *key == category)
+        .map(|(_, heading)| *heading)
+        .unwrap_or(category)
+}
+
+fn titled(title: &str, lines: &[String]) -> String {
+    format!(
+        "{}\n{}\n{}",
+        title,
+        "=".repeat(title.chars().count()),
+        lines.join("\n")
+    )
+}
+
+/// README.md for a project directory.
+pub fn readme(project: &Project) -> String {
+    let mut text = format!(
+        "# {}\n\n{}\n\nRole: {}\nTech: {}\nStatus: {} ({})\nTags: {}\n",
+        project.name,
+        project.description,
+        project.role,
+        project.tech.join(", "),
+        project.status,
+        project.date,
+        project.tags.join(", ")
+    );
+    if let Some(url) = &project.url {
+        text.push_str(&format!("Link: [{}]({})\n", display_url(url), url));
+    }
+    if let Some(repo) = &project.repo {
+        text.push_str(&format!("Source: [{}]({})\n", display_url(repo), repo));
+    }
+
+    if !project.features.is_empty() {
+        text.push_str("\n**Features:**\n");
+        for feature in &project.features {
+            text.push_str(&format!("- {}\n", feature));
+        }
+    }
+
+    if let Some(run) = &project.run {
+        text.push_str(&format!("\nRun it locally:\n```\n{}\n```", run));
+    }
+    text.trim_end().to_string()
+}
+
+/// "2023-01" as "Jan 2023". Anything unparseable is shown as is.
+pub fn month_label(date: &str) -> String {
+    const MONTHS: [&str; 12] = [
+        "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+    ];
+    let month = date
+        .split_once('-')
+        .and_then(|(year, month)| Some((year, month.parse::<usize>().ok()?)))
+        .and_then(|(year, month)| Some(format!("{} {}", MONTHS.get(month.checked_sub(1)?)?, year)));
+    month.unwrap_or_else(|| date.to_string())
+}
+
+/// Whole months from one "YYYY-MM" to another, counting both ends, so a
+/// job held from Jan to Dec of one year lasted 12 months.
+pub fn months_between(start: &str, end: &str) -> Option<u32> {
+    let months = month_number(end)? - month_number(start)? + 1;
+    u32::try_from(months).ok()
+}
+
+fn month_number(date: &str) -> Option<i32> {
+    let (year, month) = date.split_once('-')?;
+    let (year, month): (i32, i32) = (year.parse().ok()?, month.parse().ok()?);
+    (1..=12).contains(&month).then_some(year * 12 + month - 1)
+}
+
+/// A length of time in months, e.g. "3 yrs 10 mos".
+pub fn duration(months: u32) -> String {
+    let rest = match months % 12 {
+        1 => "1 mo".to_string(),
+        n => format!("{} mos", n),
+    };
+    match (months / 12, months % 12) {
+        (0, _) => rest,
+        (whole, 0) => years(whole as u8),
+        (whole, _) => format!("{} {}", years(whole as u8), rest),
+    }
+}
+
+/// A URL without its scheme, as it reads in running text.
+pub fn display_url(url: &str) -> &str {
+    url.split_once("://").map(|(_, rest)| rest).unwrap_or(url)
+}
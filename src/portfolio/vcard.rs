@@ -0,0 +1,59 @@
+use super::model::Portfolio;
+
+/// A vCard 3.0 of the contact details, which every phone and mail app
+/// can import.
+pub fn vcard(portfolio: &Portfolio) -> String {
+    let profile = &portfolio.profile;
+    let family = profile
+        .name
+        .strip_prefix(&profile.first_name)
+        .unwrap_or_default()
+        .trim();
+
+    let mut lines = vec![
+        "BEGIN:VCARD".to_string(),
+        "VERSION:3.0".to_string(),
+        format!("N:{};{};;;", escape(family), escape(&profile.first_name)),
+        format!("FN:{}", escape(&profile.name)),
+        format!("TITLE:{}", escape(&profile.title)),
+    ];
+    if let Some(job) = portfolio.experience.iter().find(|job| job.end.is_none()) {
+        lines.push(format!("ORG:{}", escape(&job.company)));
+    }
+    for url in portfolio.contact.iter().filter_map(|m| m.url.as_deref()) {
+        match url.strip_prefix("mailto:") {
+            Some(email) => lines.push(format!("EMAIL;TYPE=INTERNET:{}", escape(email))),
+            None => lines.push(format!("URL:{}", escape(url))),
+        }
+    }
+    lines.push(format!("NOTE:{}", escape(&profile.tagline)));
+    lines.push("END:VCARD".to_string());
+    content_lines(&lines)
+}
+
+/// Escape a value for vCard and iCalendar text fields.
+pub fn escape(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace('\n', "\\n")
+}
+
+/// Join lines with CRLF, folding any longer than 75 bytes onto
+/// continuation lines that start with a space, as both formats require.
+pub fn content_lines(lines: &[String]) -> String {
+    let mut out = String::new();
+    for line in lines {
+        let mut length = 0;
+        for c in line.chars() {
+            if length + c.len_utf8() > 75 {
+                out.push_str("\r\n ");
+                length = 1;
+            }
+            out.push(c);
+            length += c.len_utf8();
+        }
+        out.push_str("\r\n");
+    }
+    out
+}
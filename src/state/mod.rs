@@ -1 +1,3 @@
+pub mod shell;
 pub mod theme;
+pub mod viewport;
@@ -0,0 +1,39 @@
+use crate::commands::search::Search;
+use crate::filesystem::model::HOME;
+
+/// Where the visitor is in the filesystem, and where they have been.
+#[derive(Clone, Debug)]
+pub struct Shell {
+    pub cwd: String,
+    /// The previous working directory, for `cd -`
+    pub oldpwd: Option<String>,
+    /// Directories saved by `pushd`, most recent first
+    pub dir_stack: Vec<String>,
+    /// Links in the most recent output, for `open <n>`
+    pub links: Vec<String>,
+    /// The full-text index, and the results `search --open <n>` picks from
+    pub search: Search,
+}
+
+impl Shell {
+    pub fn new() -> Self {
+        Self {
+            cwd: HOME.to_string(),
+            oldpwd: None,
+            dir_stack: Vec::new(),
+            links: Vec::new(),
+            search: Search::default(),
+        }
+    }
+
+    /// Move to an already resolved directory, remembering where we came from.
+    pub fn change_dir(&mut self, path: String) {
+        let previous = std::mem::replace(&mut self.cwd, path);
+        self.oldpwd = Some(previous);
+    }
+
+    /// The current directory followed by the saved stack, as `dirs` shows it.
+    pub fn dirs(&self) -> impl Iterator<Item = &String> {
+        std::iter::once(&self.cwd).chain(self.dir_stack.iter())
+    }
+}
@@ -0,0 +1,22 @@
+// Matches --font-size * --line-height in main.css
+const LINE_HEIGHT_PX: f64 = 14.0 * 1.6;
+// Width of one JetBrains Mono cell at --font-size
+const CHAR_WIDTH_PX: f64 = 14.0 * 0.6;
+
+/// Approximate number of text rows that fit in the browser window.
+pub fn rows() -> usize {
+    web_sys::window()
+        .and_then(|w| w.inner_height().ok())
+        .and_then(|h| h.as_f64())
+        .map(|h| (h / LINE_HEIGHT_PX) as usize)
+        .unwrap_or(24)
+}
+
+/// Approximate number of text columns that fit in the browser window.
+pub fn columns() -> usize {
+    web_sys::window()
+        .and_then(|w| w.inner_width().ok())
+        .and_then(|w| w.as_f64())
+        .map(|w| (w / CHAR_WIDTH_PX) as usize)
+        .unwrap_or(80)
+}